@@ -0,0 +1,30 @@
+extern crate anyhow;
+use timens::{Clock, CoarseClock, MonoTime, Span, SystemClock, TscClock};
+
+const NB_ITERS: usize = 10_000_000;
+
+fn bench<F: Fn() -> T, T>(name: &str, f: F) {
+    for _i in 0..100 {
+        std::hint::black_box(f());
+    }
+    let start = MonoTime::now();
+    for _i in 0..NB_ITERS {
+        std::hint::black_box(f());
+    }
+    let dt = start.elapsed();
+    println!("{name:>16}: {:.2}ns per call", dt.to_ns() / NB_ITERS as f64);
+}
+
+fn main() -> anyhow::Result<()> {
+    let tsc_clock = TscClock::new(Span::SEC)?;
+    println!("system: {:?}", SystemClock.now());
+    println!("coarse: {:?}", CoarseClock.now());
+    println!("tsc:    {:?}", tsc_clock.now());
+    bench("Time::now", timens::Time::now);
+    bench("SystemClock", || SystemClock.now());
+    bench("CoarseClock", || CoarseClock.now());
+    bench("TscClock", || tsc_clock.now());
+    bench("MonoTime::now", MonoTime::now);
+    bench("MonoTime::now_raw", MonoTime::now_raw);
+    Ok(())
+}
@@ -8,11 +8,11 @@ fn main() -> anyhow::Result<()> {
     for _i in 0..100 {
         let _now = timens::Time::now();
     }
-    let start = timens::Time::now();
+    let start = timens::MonoTime::now();
     for _i in 1..NB_ITERS {
         let _now = timens::Time::now();
     }
-    let dt = timens::Time::now() - start;
+    let dt = start.elapsed();
     println!("dt: {:?}, per-iter: {:?}", dt, dt / (NB_ITERS as f64));
     Ok(())
 }
@@ -0,0 +1,148 @@
+use crate::{Date, Span, Time, Tz};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicI64, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// A source for the current time. `Time::now` and `Date::today` use the system
+/// clock unless another clock has been installed for the current thread with
+/// `with_clock`, e.g. a `ManualClock` to make tests deterministic.
+pub trait Clock {
+    fn now(&self) -> Time;
+
+    /// The current date in the given timezone.
+    fn today(&self, tz: Tz) -> Date {
+        self.now().to_date(tz)
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> Time {
+        (**self).now()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Box<C> {
+    fn now(&self) -> Time {
+        (**self).now()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Rc<C> {
+    fn now(&self) -> Time {
+        (**self).now()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    fn now(&self) -> Time {
+        (**self).now()
+    }
+}
+
+/// The wall clock of the operating system, this ignores the clock installed
+/// with `with_clock`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Time {
+        Time::try_from(std::time::SystemTime::now()).expect("system time out of range")
+    }
+}
+
+/// The coarse wall clock, CLOCK_REALTIME_COARSE on Linux. This is much cheaper to
+/// query than `SystemClock` but only has the resolution of the kernel tick,
+/// usually 1 to 4ms. This is the same as `SystemClock` on other systems.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CoarseClock;
+
+impl Clock for CoarseClock {
+    fn now(&self) -> Time {
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        return Time::of_int_ns_since_epoch(crate::mono_time::clock_gettime_ns(
+            libc::CLOCK_REALTIME_COARSE,
+        ));
+        #[cfg(not(any(target_os = "linux", target_os = "android")))]
+        return SystemClock.now();
+    }
+}
+
+/// A clock that always returns the same time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FixedClock(pub Time);
+
+impl Clock for FixedClock {
+    fn now(&self) -> Time {
+        self.0
+    }
+}
+
+/// A clock that only moves when it is explicitly set or advanced. It can be
+/// shared between threads, e.g. using an `Arc<ManualClock>`.
+#[derive(Debug)]
+pub struct ManualClock(AtomicI64);
+
+impl ManualClock {
+    pub fn new(time: Time) -> Self {
+        Self(AtomicI64::new(time.to_int_ns_since_epoch()))
+    }
+
+    pub fn set(&self, time: Time) {
+        self.0.store(time.to_int_ns_since_epoch(), Ordering::SeqCst)
+    }
+
+    /// Moves the clock forward by `span`, or backward if `span` is negative.
+    pub fn advance(&self, span: Span) {
+        self.0.fetch_add(span.to_int_ns(), Ordering::SeqCst);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Time {
+        Time::of_int_ns_since_epoch(self.0.load(Ordering::SeqCst))
+    }
+}
+
+thread_local! {
+    static THREAD_CLOCK: RefCell<Option<Rc<dyn Clock>>> = const { RefCell::new(None) };
+}
+
+// The number of `with_clock` calls in progress over all threads, `now` only looks
+// up the thread clock when this is not zero.
+static NB_OVERRIDES: AtomicUsize = AtomicUsize::new(0);
+
+// Restores the previous thread clock on drop, including when unwinding.
+struct RestoreClock(Option<Rc<dyn Clock>>);
+
+impl Drop for RestoreClock {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        THREAD_CLOCK.with(|c| *c.borrow_mut() = previous);
+        NB_OVERRIDES.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Runs `f` with `clock` used by `Time::now` and `Date::today` on the current
+/// thread. Calls can be nested, the previous clock is restored when `f` returns.
+pub fn with_clock<C: Clock + 'static, R, F: FnOnce() -> R>(clock: C, f: F) -> R {
+    let clock: Rc<dyn Clock> = Rc::new(clock);
+    NB_OVERRIDES.fetch_add(1, Ordering::Relaxed);
+    let previous = THREAD_CLOCK.with(|c| c.borrow_mut().replace(clock));
+    let _restore = RestoreClock(previous);
+    f()
+}
+
+/// The current time using the clock installed with `with_clock` if any, and the
+/// system clock otherwise.
+pub(crate) fn now() -> Time {
+    // A thread only sees a zero count when it has no clock installed itself.
+    if NB_OVERRIDES.load(Ordering::Relaxed) == 0 {
+        return SystemClock.now();
+    }
+    let clock = THREAD_CLOCK.with(|c| c.borrow().clone());
+    match clock {
+        Some(clock) => clock.now(),
+        None => SystemClock.now(),
+    }
+}
@@ -129,6 +129,25 @@ impl DayOfWeek {
     pub const fn is_weekend(self) -> bool {
         !self.is_weekday()
     }
+
+    /// Conversion from an int following ISO 8601, 1 for Monday, 2 for Tuesday,
+    /// etc, and 7 for Sunday.
+    pub const fn of_iso_u8(i: u8) -> Option<DayOfWeek> {
+        match i {
+            0 => None,
+            7 => Some(Self::Sun),
+            i => Self::of_u8(i),
+        }
+    }
+
+    /// Conversion to an int following ISO 8601, 1 for Monday, 2 for Tuesday,
+    /// etc, and 7 for Sunday.
+    pub const fn to_iso_u8(self) -> u8 {
+        match self {
+            Self::Sun => 7,
+            other => other.to_u8(),
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -218,6 +237,10 @@ impl std::fmt::Display for Date {
 pub enum DateError {
     InvalidYear(u32),
     InvalidDayForMonth(u32, Month, u8),
+    InvalidDayOfYear(u32, u16),
+    InvalidIsoWeek(i32, u8),
+    InvalidIsoYear(i32),
+    ParseImmCodeError,
     ParseIntError,
     ParseMonthError,
     ParseError,
@@ -300,11 +323,45 @@ impl Date {
         }
     };
 
+    /// The earliest supported date, 0000-01-01.
+    pub const MIN: Self = Self((1 << 8) | 1);
+    /// The latest supported date, 9999-12-31.
+    pub const MAX: Self = Self((9999 << 16) | (12 << 8) | 31);
+
     /// Add a number of days to this date.
     pub fn add_days(self, n: i32) -> Self {
         self + n
     }
 
+    /// Add a number of days to this date, returns `None` if the result is out of `[MIN, MAX]`.
+    pub fn checked_add(self, n: i32) -> Option<Self> {
+        let days = Days::of_date(self).0.checked_add(n)?;
+        Days(days).checked_to_date()
+    }
+
+    /// Subtract a number of days from this date, returns `None` if the result is out of
+    /// `[MIN, MAX]`.
+    pub fn checked_sub(self, n: i32) -> Option<Self> {
+        let days = Days::of_date(self).0.checked_sub(n)?;
+        Days(days).checked_to_date()
+    }
+
+    pub fn saturating_add(self, n: i32) -> Self {
+        match self.checked_add(n) {
+            Some(date) => date,
+            None if n < 0 => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    pub fn saturating_sub(self, n: i32) -> Self {
+        match self.checked_sub(n) {
+            Some(date) => date,
+            None if n > 0 => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
     /// Add some number of months to a date.
     /// This returns the date with the last day of the month in the case where the
     /// actual date would be invalid, e.g. adding a month to Jan 31 results in Feb 28
@@ -341,22 +398,86 @@ impl Date {
     /// The day of the week for this date.
     pub fn day_of_week(self) -> DayOfWeek {
         let m = self.month_int();
-        let y = if m < 3 { self.year() - 1 } else { self.year() };
-        let y = y as i32;
-        let d = (y + y / 4 - y / 100
-            + y / 400
+        let y = self.year() as i32;
+        let y = if m < 3 { y - 1 } else { y };
+        let d = (y + y.div_euclid(4) - y.div_euclid(100)
+            + y.div_euclid(400)
             + Self::DAYOFWEEK_TABLE[(m - 1) as usize]
             + self.day() as i32)
-            % 7;
+            .rem_euclid(7);
         DayOfWeek::of_u8(d as u8).unwrap()
     }
 
+    /// The day of the year for this date, between 1 and 366.
+    pub fn day_of_year(self) -> u16 {
+        (Days::of_date(self) - Days::jan1(self.year() as i32)) as u16 + 1
+    }
+
+    /// The quarter for this date, between 1 and 4.
+    pub const fn quarter(self) -> u8 {
+        (self.month_int() - 1) / 3 + 1
+    }
+
+    /// The ISO 8601 week date for this date, i.e. the week-numbering year, the
+    /// week number between 1 and 53, and the day of the week.
+    /// The week-numbering year can differ from the calendar year for the first
+    /// and last few days of a year, e.g. 2021-01-03 is in week 53 of 2020. This
+    /// is an `i32` as 0000-01-01 and 0000-01-02 belong to the year -1.
+    pub fn iso_week_date(self) -> (i32, u8, DayOfWeek) {
+        let days = Days::of_date(self);
+        let day_of_week = days.day_of_week();
+        // The week-numbering year is the year of the Thursday of the current week.
+        let thursday = days + 4 - day_of_week.to_iso_u8() as i32;
+        let year = thursday.year();
+        let week = (thursday - Days::jan1(year)) / 7 + 1;
+        (year, week as u8, day_of_week)
+    }
+
+    /// The ISO 8601 week-numbering year for this date.
+    pub fn iso_week_year(self) -> i32 {
+        self.iso_week_date().0
+    }
+
+    /// The ISO 8601 week number for this date, between 1 and 53.
+    pub fn iso_week(self) -> u8 {
+        self.iso_week_date().1
+    }
+
+    /// Creates a date from an ISO 8601 week date, i.e. a week-numbering year, a
+    /// week number between 1 and 53, and a day of the week.
+    pub fn of_iso_week(year: i32, week: u8, day_of_week: DayOfWeek) -> Result<Self, DateError> {
+        // The week-numbering year of `Date::MIN` is -1.
+        if year < Self::MIN.iso_week_date().0 || year > Self::MAX.iso_week_date().0 {
+            return Err(DateError::InvalidIsoYear(year));
+        }
+        if week == 0 || week > Days::iso_weeks_in_year(year) {
+            return Err(DateError::InvalidIsoWeek(year, week));
+        }
+        let monday = Days::iso_week1_monday(year);
+        (monday + (week as i32 - 1) * 7 + day_of_week.to_iso_u8() as i32 - 1)
+            .checked_to_date()
+            .ok_or(DateError::InvalidIsoWeek(year, week))
+    }
+
+    /// Creates a date from a year and a day of the year, between 1 and 366.
+    pub fn of_ordinal(year: u32, day_of_year: u16) -> Result<Self, DateError> {
+        if year > 9999 {
+            return Err(DateError::InvalidYear(year));
+        }
+        let days_in_year = if is_leap_year(year) { 366 } else { 365 };
+        if day_of_year == 0 || day_of_year > days_in_year {
+            return Err(DateError::InvalidDayOfYear(year, day_of_year));
+        }
+        (Days::jan1(year as i32) + day_of_year as i32 - 1).to_date()
+    }
+
     /// The number of days since epoch (1970-01-01).
     pub fn of_days_since_epoch(d: i32) -> Self {
         Self::UNIX_EPOCH + d
     }
 
-    /// The current date in the given timezone, this calls Time::now.
+    /// The current date in the given timezone, this calls Time::now so respects
+    /// the clock installed with `with_clock`.
     pub fn today(tz: crate::Tz) -> Self {
         crate::Time::now().to_date(tz)
     }
@@ -441,12 +562,179 @@ impl Date {
     }
 }
 
+/// Whether the starting date itself can be returned when searching for a day of the week.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Inclusivity {
+    Inclusive,
+    Exclusive,
+}
+
+impl Date {
+    /// The first day of the month for this date.
+    pub fn first_day_of_month(self) -> Self {
+        Self::create(self.year(), self.month(), 1).unwrap()
+    }
+
+    /// The last day of the month for this date.
+    pub fn last_day_of_month(self) -> Self {
+        let month = self.month();
+        Self::create(self.year(), month, month.days_in_month(self.year())).unwrap()
+    }
+
+    /// The first day of the quarter for this date, e.g. Apr 1 for May 12.
+    pub fn first_day_of_quarter(self) -> Self {
+        let month = Month::of_u8(3 * self.quarter() - 2).unwrap();
+        Self::create(self.year(), month, 1).unwrap()
+    }
+
+    /// The last day of the quarter for this date, e.g. Jun 30 for May 12.
+    pub fn last_day_of_quarter(self) -> Self {
+        let month = Month::of_u8(3 * self.quarter()).unwrap();
+        Self::create(self.year(), month, month.days_in_month(self.year())).unwrap()
+    }
+
+    /// The first day of the year for this date.
+    pub fn first_day_of_year(self) -> Self {
+        Self::create(self.year(), Month::Jan, 1).unwrap()
+    }
+
+    /// The last day of the year for this date.
+    pub fn last_day_of_year(self) -> Self {
+        Self::create(self.year(), Month::Dec, 31).unwrap()
+    }
+
+    /// The first date on or after `self` that falls on the given day of the week,
+    /// `self` is excluded from the search when `inclusivity` is `Exclusive`.
+    /// Returns `None` if this date would be after `Date::MAX`.
+    pub fn next_weekday(self, day_of_week: DayOfWeek, inclusivity: Inclusivity) -> Option<Self> {
+        let start = match inclusivity {
+            Inclusivity::Inclusive => self,
+            Inclusivity::Exclusive => self.checked_add(1)?,
+        };
+        let diff = day_of_week.to_u8() as i32 - start.day_of_week().to_u8() as i32;
+        start.checked_add(diff.rem_euclid(7))
+    }
+
+    /// The last date on or before `self` that falls on the given day of the week,
+    /// `self` is excluded from the search when `inclusivity` is `Exclusive`.
+    /// Returns `None` if this date would be before `Date::MIN`.
+    pub fn prev_weekday(self, day_of_week: DayOfWeek, inclusivity: Inclusivity) -> Option<Self> {
+        let start = match inclusivity {
+            Inclusivity::Inclusive => self,
+            Inclusivity::Exclusive => self.checked_sub(1)?,
+        };
+        let diff = start.day_of_week().to_u8() as i32 - day_of_week.to_u8() as i32;
+        start.checked_sub(diff.rem_euclid(7))
+    }
+
+    /// The n-th occurrence of the given day of the week in the month for this date,
+    /// e.g. the third Friday of the month for `n = 3`. Negative values of `n` count
+    /// from the end of the month, `n = -1` being the last occurrence.
+    /// Returns `None` when `n` is 0 or when there is no such day in the month.
+    pub fn nth_weekday_of_month(self, n: i32, day_of_week: DayOfWeek) -> Option<Self> {
+        // A month always has at least 4 and at most 5 occurrences of each day of the week.
+        let date = match n {
+            1..=5 => {
+                let first =
+                    self.first_day_of_month().next_weekday(day_of_week, Inclusivity::Inclusive)?;
+                first.checked_add(7 * (n - 1))?
+            }
+            -5..=-1 => {
+                let last =
+                    self.last_day_of_month().prev_weekday(day_of_week, Inclusivity::Inclusive)?;
+                last.checked_add(7 * (n + 1))?
+            }
+            _ => return None,
+        };
+        if date.year() == self.year() && date.month() == self.month() {
+            Some(date)
+        } else {
+            None
+        }
+    }
+}
+
+impl Date {
+    /// The date of Western Easter Sunday for the given year, using the Gregorian
+    /// computus for all years including the ones before 1583.
+    pub fn easter_western(year: u32) -> Result<Self, DateError> {
+        if year > 9999 {
+            return Err(DateError::InvalidYear(year));
+        }
+        // Anonymous Gregorian algorithm, from Meeus' Astronomical Algorithms.
+        let a = year % 19;
+        let b = year / 100;
+        let c = year % 100;
+        let d = b / 4;
+        let e = b % 4;
+        let f = (b + 8) / 25;
+        let g = (b - f + 1) / 3;
+        let h = (19 * a + b - d - g + 15) % 30;
+        let i = c / 4;
+        let k = c % 4;
+        let l = (32 + 2 * e + 2 * i - h - k) % 7;
+        let m = (a + 11 * h + 22 * l) / 451;
+        let month = (h + l - 7 * m + 114) / 31;
+        let day = (h + l - 7 * m + 114) % 31 + 1;
+        Self::create(year, Month::of_u8(month as u8).unwrap(), day as u8)
+    }
+
+    /// The date of Orthodox Easter Sunday for the given year. This is computed
+    /// in the Julian calendar and returned as a (proleptic Gregorian) `Date`, so
+    /// e.g. Orthodox Easter 2021 is 2021-05-02.
+    pub fn easter_orthodox(year: u32) -> Result<Self, DateError> {
+        if year > 9999 {
+            return Err(DateError::InvalidYear(year));
+        }
+        // Meeus' Julian algorithm.
+        let a = year % 4;
+        let b = year % 7;
+        let c = year % 19;
+        let d = (19 * c + 15) % 30;
+        let e = (2 * a + 4 * b + 34 - d) % 7;
+        let month = (d + e + 114) / 31;
+        let day = (d + e + 114) % 31 + 1;
+        Days::of_julian(year as i32, month as i32, day as i32).to_date()
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Days(i32);
 
 impl Days {
+    // Days are counted from March 1 of year 0, `of_year` returns the first day
+    // of the year starting on March 1 of year `y`.
     const fn of_year(y: i32) -> Self {
-        Days(365 * y + y / 4 + y / 400 - y / 100)
+        Days(365 * y + y.div_euclid(4) + y.div_euclid(400) - y.div_euclid(100))
+    }
+
+    // January 1 is the 307th day of the year starting on March 1 of the previous year.
+    const fn jan1(y: i32) -> Self {
+        Days(Self::of_year(y - 1).0 + 306)
+    }
+
+    // The Monday of the first ISO week of year `y`, i.e. of the week holding January 4.
+    fn iso_week1_monday(y: i32) -> Self {
+        let jan4 = Self::jan1(y) + 3;
+        jan4 + 1 - jan4.day_of_week().to_iso_u8() as i32
+    }
+
+    fn iso_weeks_in_year(y: i32) -> u8 {
+        ((Self::iso_week1_monday(y + 1) - Self::iso_week1_monday(y)) / 7) as u8
+    }
+
+    // The calendar year for this day, this is not restricted to the range
+    // supported by `Date`.
+    fn year(self) -> i32 {
+        self.to_ymd().0
+    }
+
+    // Converts a date expressed in the Julian calendar. The Julian and Gregorian
+    // calendars agree between 0200-03-01 and 0300-02-28.
+    const fn of_julian(y: i32, m: i32, d: i32) -> Days {
+        let m = (m + 9) % 12;
+        let y = y - m / 10;
+        Days(365 * y + y.div_euclid(4) + (((m * 306) + 5) / 10) + d - 3)
     }
 
     pub const fn of_date(date: Date) -> Days {
@@ -455,7 +743,7 @@ impl Days {
         Days(Self::of_year(y).0 + (((m * 306) + 5) / 10) + date.day() as i32 - 1)
     }
 
-    pub fn to_date(self) -> Result<Date, DateError> {
+    fn to_ymd(self) -> (i32, i32, i32) {
         let y = ((self.0 as i64 * 10_000 + 14_780) / 3_652_425) as i32;
         let ddd = self.0 - Self::of_year(y).0;
         let (y, ddd) = if ddd < 0 {
@@ -468,11 +756,26 @@ impl Days {
         let y = y + ((mi + 2) / 12);
         let m = ((mi + 2) % 12) + 1;
         let d = ddd - (((mi * 306) + 5) / 10) + 1;
+        (y, m, d)
+    }
+
+    pub fn to_date(self) -> Result<Date, DateError> {
+        let (y, m, d) = self.to_ymd();
         Date::create(y as u32, Month::of_u8(m as u8).unwrap(), d as u8)
     }
 
+    // Similar to `to_date` but returns `None` out of `[Date::MIN, Date::MAX]`, the
+    // range is checked first as `to_ymd` overflows for large day counts.
+    fn checked_to_date(self) -> Option<Date> {
+        if Self::of_date(Date::MIN).0 <= self.0 && self.0 <= Self::of_date(Date::MAX).0 {
+            self.to_date().ok()
+        } else {
+            None
+        }
+    }
+
     pub fn day_of_week(self) -> DayOfWeek {
-        DayOfWeek::of_u8(((self.0 + 3) % 7) as u8).unwrap()
+        DayOfWeek::of_u8((self.0 + 3).rem_euclid(7) as u8).unwrap()
     }
 
     pub fn is_weekday(self) -> bool {
@@ -617,8 +920,29 @@ impl Iterator for DatesBetween {
             None
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.last_day - self.current_day + 1).max(0) as usize;
+        (len, Some(len))
+    }
 }
 
+impl DoubleEndedIterator for DatesBetween {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.current_day <= self.last_day {
+            let res = self.last_day.to_date().unwrap();
+            self.last_day -= 1;
+            Some(res)
+        } else {
+            None
+        }
+    }
+}
+
+impl ExactSizeIterator for DatesBetween {}
+
+impl std::iter::FusedIterator for DatesBetween {}
+
 impl Date {
     /// Lists all the dates between two dates (inclusive).
     pub fn dates_between(lo: Self, up: Self) -> DatesBetween {
@@ -649,8 +973,27 @@ impl Iterator for WeekdaysBetween {
         }
         None
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some((self.last_day - self.current_day + 1).max(0) as usize))
+    }
 }
 
+impl DoubleEndedIterator for WeekdaysBetween {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while self.current_day <= self.last_day {
+            let day = self.last_day;
+            self.last_day -= 1;
+            if day.is_weekday() {
+                return Some(day.to_date().unwrap());
+            }
+        }
+        None
+    }
+}
+
+impl std::iter::FusedIterator for WeekdaysBetween {}
+
 impl Date {
     /// Lists all the weekdays between two dates (inclusive).
     pub fn weekdays_between(lo: Self, up: Self) -> WeekdaysBetween {
@@ -664,26 +1007,44 @@ impl Date {
 }
 
 pub struct BusinessDaysBetween<F: Fn(Date) -> bool> {
-    current_date: Date,
-    last_date: Date,
+    current_day: Days,
+    last_day: Days,
     is_business_day: F,
 }
 
 impl<T: Fn(Date) -> bool> Iterator for BusinessDaysBetween<T> {
     type Item = Date;
     fn next(&mut self) -> Option<Self::Item> {
-        while self.current_date <= self.last_date {
-            if (self.is_business_day)(self.current_date) {
-                let res = self.current_date;
-                self.current_date += 1;
-                return Some(res);
+        while self.current_day <= self.last_day {
+            let date = self.current_day.to_date().unwrap();
+            self.current_day += 1;
+            if (self.is_business_day)(date) {
+                return Some(date);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some((self.last_day - self.current_day + 1).max(0) as usize))
+    }
+}
+
+impl<T: Fn(Date) -> bool> DoubleEndedIterator for BusinessDaysBetween<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while self.current_day <= self.last_day {
+            let date = self.last_day.to_date().unwrap();
+            self.last_day -= 1;
+            if (self.is_business_day)(date) {
+                return Some(date);
             }
-            self.current_date += 1;
         }
         None
     }
 }
 
+impl<T: Fn(Date) -> bool> std::iter::FusedIterator for BusinessDaysBetween<T> {}
+
 impl Date {
     /// Lists all the business days between two dates (inclusive).
     pub fn business_days_between<F>(
@@ -694,7 +1055,11 @@ impl Date {
     where
         F: Fn(Self) -> bool,
     {
-        BusinessDaysBetween { current_date: lo, last_date: up, is_business_day }
+        BusinessDaysBetween {
+            current_day: Days::of_date(lo),
+            last_day: Days::of_date(up),
+            is_business_day,
+        }
     }
 
     /// Lists all the business days from the `self` date until `up` (inclusive).
@@ -0,0 +1,288 @@
+use crate::ofday::ParseOfDayError;
+use crate::{Date, DateError, OfDay, Span, Time, Tz, TzError};
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+/// A date and a time of day without a timezone, e.g. "2021-08-27 00:09:46.5".
+/// The time of day is always before the start of the next day, 24:00 is
+/// normalized to 00:00 on the next day.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTime {
+    date: Date,
+    ofday: OfDay,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseDateTimeError {
+    NoSeparator,
+    DateError(DateError),
+    OfDayError(ParseOfDayError),
+}
+
+impl std::fmt::Display for ParseDateTimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl std::error::Error for ParseDateTimeError {}
+
+impl From<DateError> for ParseDateTimeError {
+    fn from(e: DateError) -> Self {
+        Self::DateError(e)
+    }
+}
+
+impl From<ParseOfDayError> for ParseDateTimeError {
+    fn from(e: ParseOfDayError) -> Self {
+        Self::OfDayError(e)
+    }
+}
+
+const DAY_NS: i128 = Span::DAY.to_int_ns() as i128;
+
+impl DateTime {
+    /// Normalizes 24:00 to 00:00 on the next day, this fails for 24:00 on
+    /// `Date::MAX` as the next day cannot be represented.
+    pub fn create(date: Date, ofday: OfDay) -> Result<Self, DateError> {
+        if ofday == OfDay::START_OF_NEXT_DAY {
+            let date = date.checked_add(1).ok_or(DateError::InvalidYear(date.year() + 1))?;
+            Ok(Self { date, ofday: OfDay::START_OF_DAY })
+        } else {
+            Ok(Self { date, ofday })
+        }
+    }
+
+    pub const fn date(self) -> Date {
+        self.date
+    }
+
+    pub const fn ofday(self) -> OfDay {
+        self.ofday
+    }
+
+    /// The local date and time of day of `time` in the given timezone.
+    pub fn of_time(time: Time, tz: Tz) -> Self {
+        // The time of day is always before 24:00 here.
+        let (date, ofday) = time.to_date_ofday(tz);
+        Self { date, ofday }
+    }
+
+    pub fn of_time_gmt(time: Time) -> Self {
+        let (date, ofday) = time.to_date_ofday_gmt();
+        Self { date, ofday }
+    }
+
+    /// The time at which the local date and time of day are `self` in the given
+    /// timezone, this fails if this local time is skipped or repeated because of
+    /// a daylight saving change.
+    pub fn to_time(self, tz: Tz) -> Result<Time, TzError> {
+        Time::of_date_ofday(self.date, self.ofday, tz)
+    }
+
+    /// Similar to `to_time` but always returns a time, see `Time::of_date_ofday_lenient`.
+    pub fn to_time_lenient(self, tz: Tz) -> Time {
+        Time::of_date_ofday_lenient(self.date, self.ofday, tz)
+    }
+
+    pub fn to_time_gmt(self) -> Time {
+        Time::of_date_ofday_gmt(self.date, self.ofday)
+    }
+
+    // The number of nanoseconds since 0000-01-01 00:00.
+    fn to_ns(self) -> i128 {
+        (self.date - Date::MIN) as i128 * DAY_NS + self.ofday.to_ns_since_midnight() as i128
+    }
+
+    fn of_ns(ns: i128) -> Option<Self> {
+        let days = i32::try_from(ns.div_euclid(DAY_NS)).ok()?;
+        let ofday = OfDay::of_ns_since_midnight(ns.rem_euclid(DAY_NS) as i64);
+        Some(Self { date: Date::MIN.checked_add(days)?, ofday })
+    }
+
+    /// Adds a span, rolling over days. Returns `None` if the resulting date is out
+    /// of the supported range.
+    pub fn checked_add(self, span: Span) -> Option<Self> {
+        Self::of_ns(self.to_ns() + span.to_int_ns() as i128)
+    }
+
+    /// Subtracts a span, rolling over days. Returns `None` if the resulting date is
+    /// out of the supported range.
+    pub fn checked_sub(self, span: Span) -> Option<Self> {
+        Self::of_ns(self.to_ns() - span.to_int_ns() as i128)
+    }
+
+    /// The span between two date-times, returns `None` if it cannot be represented
+    /// as a `Span`, i.e. if it exceeds roughly 292 years.
+    pub fn checked_diff(self, other: Self) -> Option<Span> {
+        // Spans are restricted to [MIN_VALUE, MAX_VALUE], which excludes i64::MIN.
+        let ns = i64::try_from(self.to_ns() - other.to_ns()).ok()?;
+        if ns == i64::MIN {
+            None
+        } else {
+            Some(Span::of_int_ns(ns))
+        }
+    }
+}
+
+impl TryFrom<(Date, OfDay)> for DateTime {
+    type Error = DateError;
+
+    fn try_from((date, ofday): (Date, OfDay)) -> Result<Self, Self::Error> {
+        Self::create(date, ofday)
+    }
+}
+
+impl From<DateTime> for (Date, OfDay) {
+    fn from(date_time: DateTime) -> Self {
+        (date_time.date, date_time.ofday)
+    }
+}
+
+impl std::fmt::Display for DateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.date, self.ofday)
+    }
+}
+
+impl std::fmt::Debug for DateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::str::FromStr for DateTime {
+    type Err = ParseDateTimeError;
+
+    // The date and time of day can be separated by a space or a 'T'.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (date, ofday) = s
+            .split_once(' ')
+            .or_else(|| s.split_once('T'))
+            .ok_or(ParseDateTimeError::NoSeparator)?;
+        Ok(Self::create(Date::from_str(date)?, OfDay::from_str(ofday)?)?)
+    }
+}
+
+impl Add<Span> for DateTime {
+    type Output = Self;
+
+    fn add(self, other: Span) -> Self {
+        self.checked_add(other).expect("date out of range")
+    }
+}
+
+impl AddAssign<Span> for DateTime {
+    fn add_assign(&mut self, other: Span) {
+        *self = *self + other
+    }
+}
+
+impl Sub<Span> for DateTime {
+    type Output = Self;
+
+    fn sub(self, other: Span) -> Self {
+        self.checked_sub(other).expect("date out of range")
+    }
+}
+
+impl SubAssign<Span> for DateTime {
+    fn sub_assign(&mut self, other: Span) {
+        *self = *self - other
+    }
+}
+
+impl Sub for DateTime {
+    type Output = Span;
+
+    fn sub(self, other: Self) -> Span {
+        self.checked_diff(other).expect("date-time difference out of the span range")
+    }
+}
+
+impl Time {
+    /// The local date and time of day in the given timezone.
+    pub fn to_date_time(self, tz: Tz) -> DateTime {
+        DateTime::of_time(self, tz)
+    }
+}
+
+// Same layout as the `{ date; ofday }` record, reading goes through `create` so
+// that 24:00 is normalized.
+#[cfg(feature = "binio")]
+mod binio {
+    use super::DateTime;
+    use crate::{Date, OfDay};
+
+    impl binprot::BinProtRead for DateTime {
+        fn binprot_read<R>(r: &mut R) -> Result<Self, binprot::Error>
+        where
+            R: std::io::Read + ?Sized,
+        {
+            let date: Date = binprot::BinProtRead::binprot_read(r)?;
+            let ofday: OfDay = binprot::BinProtRead::binprot_read(r)?;
+            DateTime::create(date, ofday).map_err(|err| binprot::Error::CustomError(Box::new(err)))
+        }
+    }
+
+    impl binprot::BinProtWrite for DateTime {
+        fn binprot_write<W>(&self, w: &mut W) -> Result<(), std::io::Error>
+        where
+            W: std::io::Write,
+        {
+            binprot::BinProtWrite::binprot_write(&self.date, w)?;
+            binprot::BinProtWrite::binprot_write(&self.ofday, w)
+        }
+    }
+}
+
+#[cfg(feature = "sexp")]
+mod sexp {
+    use super::DateTime;
+    use crate::{Date, OfDay};
+    use rsexp::Sexp;
+
+    // Same format as `Time`, a list with the date and the time of day.
+    impl rsexp::SexpOf for DateTime {
+        fn sexp_of(&self) -> Sexp {
+            rsexp::SexpOf::sexp_of(&(self.date, self.ofday))
+        }
+    }
+
+    impl rsexp::OfSexp for DateTime {
+        fn of_sexp(sexp: &Sexp) -> Result<Self, rsexp::IntoSexpError> {
+            let (date, ofday): (Date, OfDay) = rsexp::OfSexp::of_sexp(sexp)?;
+            DateTime::create(date, ofday)
+                .map_err(|err| rsexp::IntoSexpError::StringConversionError { err: err.to_string() })
+        }
+    }
+}
+
+#[cfg(feature = "with_serde")]
+mod with_serde {
+    use super::DateTime;
+    use crate::{Date, OfDay};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::str::FromStr;
+
+    impl Serialize for DateTime {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                self.to_string().serialize(serializer)
+            } else {
+                (self.date, self.ofday).serialize(serializer)
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for DateTime {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            if deserializer.is_human_readable() {
+                let s = String::deserialize(deserializer)?;
+                DateTime::from_str(&s).map_err(serde::de::Error::custom)
+            } else {
+                let (date, ofday) = <(Date, OfDay)>::deserialize(deserializer)?;
+                DateTime::create(date, ofday).map_err(serde::de::Error::custom)
+            }
+        }
+    }
+}
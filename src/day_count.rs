@@ -0,0 +1,121 @@
+use crate::{is_leap_year, Date, Month};
+
+/// Day count conventions used to compute year fractions, e.g. for accrued interest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DayCount {
+    /// Actual number of days divided by 360.
+    Act360,
+    /// Actual number of days divided by 365.
+    Act365Fixed,
+    /// Days in leap years divided by 366 plus days in other years divided by 365.
+    ActActIsda,
+    /// Actual number of days divided by the number of days in the reference coupon
+    /// period times the number of periods per year.
+    ActActIcma,
+    /// 30/360 US with the end of month adjustments for February.
+    Thirty360Us,
+    /// 30E/360, also known as Eurobond basis.
+    Thirty360E,
+    /// Number of business days divided by 252.
+    Bus252,
+}
+
+fn days_360(y1: u32, m1: u8, d1: u8, y2: u32, m2: u8, d2: u8) -> f64 {
+    let days = 360 * (y2 as i64 - y1 as i64) + 30 * (m2 as i64 - m1 as i64) + d2 as i64 - d1 as i64;
+    days as f64 / 360.
+}
+
+fn is_last_day_of_feb(date: Date) -> bool {
+    date.month() == Month::Feb && date == date.last_day_of_month()
+}
+
+impl DayCount {
+    /// The year fraction between `start` and `end` for this convention, this is
+    /// negative when `end` is before `start`.
+    /// For ACT/ACT ICMA, `[start, end]` is used as the reference period so this
+    /// only applies to regular coupon periods, see `year_fraction_with_reference`
+    /// for stubs. For BUS/252, all weekdays are considered as business days, see
+    /// `bus_252` to take holidays into account.
+    pub fn year_fraction(self, start: Date, end: Date) -> f64 {
+        self.year_fraction_with_reference(start, end, start, end)
+    }
+
+    /// The year fraction between `start` and `end` for this convention, where
+    /// `[ref_start, ref_end]` is the regular coupon period holding `start` and `end`.
+    /// The reference period is only used by ACT/ACT ICMA.
+    pub fn year_fraction_with_reference(
+        self,
+        start: Date,
+        end: Date,
+        ref_start: Date,
+        ref_end: Date,
+    ) -> f64 {
+        if end < start {
+            return -self.year_fraction_with_reference(end, start, ref_start, ref_end);
+        }
+        match self {
+            Self::Act360 => (end - start) as f64 / 360.,
+            Self::Act365Fixed => (end - start) as f64 / 365.,
+            Self::ActActIsda => {
+                let days_in_year = |y: u32| if is_leap_year(y) { 366. } else { 365. };
+                if start.year() == end.year() {
+                    return (end - start) as f64 / days_in_year(start.year());
+                }
+                let first = (start.last_day_of_year() - start + 1) as f64;
+                let last = (end - end.first_day_of_year()) as f64;
+                let full_years = end.year() - start.year() - 1;
+                first / days_in_year(start.year())
+                    + full_years as f64
+                    + last / days_in_year(end.year())
+            }
+            Self::ActActIcma => {
+                if ref_end <= ref_start {
+                    return 0.;
+                }
+                let months = (ref_end.year() as i64 - ref_start.year() as i64) * 12
+                    + ref_end.month_int() as i64
+                    - ref_start.month_int() as i64;
+                (end - start) as f64 / (ref_end - ref_start) as f64 * months as f64 / 12.
+            }
+            Self::Thirty360Us => {
+                let mut d1 = start.day();
+                let mut d2 = end.day();
+                if is_last_day_of_feb(start) {
+                    if is_last_day_of_feb(end) {
+                        d2 = 30;
+                    }
+                    d1 = 30;
+                }
+                if d2 == 31 && d1 >= 30 {
+                    d2 = 30;
+                }
+                if d1 == 31 {
+                    d1 = 30;
+                }
+                days_360(start.year(), start.month_int(), d1, end.year(), end.month_int(), d2)
+            }
+            Self::Thirty360E => {
+                let d1 = start.day().min(30);
+                let d2 = end.day().min(30);
+                days_360(start.year(), start.month_int(), d1, end.year(), end.month_int(), d2)
+            }
+            Self::Bus252 => Self::bus_252(start, end, |d| d.is_weekday()),
+        }
+    }
+
+    /// The BUS/252 year fraction, i.e. the number of business days from `start`
+    /// (inclusive) to `end` (exclusive) divided by 252.
+    pub fn bus_252<F>(start: Date, end: Date, is_business_day: F) -> f64
+    where
+        F: Fn(Date) -> bool,
+    {
+        if end < start {
+            return -Self::bus_252(end, start, is_business_day);
+        }
+        if end == start {
+            return 0.;
+        }
+        let days = Date::business_days_between(start, end - 1, is_business_day).count();
+        days as f64 / 252.
+    }
+}
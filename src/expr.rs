@@ -0,0 +1,193 @@
+use crate::ofday::ParseOfDayError;
+use crate::span::ParseSpanError;
+use crate::{Date, DateError, DayOfWeek, Inclusivity, OfDay, Span, Time, Tz};
+use std::str::FromStr;
+
+/// The result of parsing an expression, expressions such as "yesterday" or
+/// "2021-01-16" result in a date, and expressions such as "now-15m" or
+/// "today 16:00" result in a time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DateOrTime {
+    Date(Date),
+    Time(Time),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseExprError {
+    EmptyString,
+    UnexpectedToken(String),
+    MissingDayOfWeek(String),
+    OfDayAfterTime(String),
+    OutOfRange(String),
+    ParseSpanError(ParseSpanError),
+    DateError(DateError),
+    ParseOfDayError(ParseOfDayError),
+}
+
+impl std::fmt::Display for ParseExprError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl std::error::Error for ParseExprError {}
+
+impl From<ParseSpanError> for ParseExprError {
+    fn from(e: ParseSpanError) -> Self {
+        Self::ParseSpanError(e)
+    }
+}
+
+impl From<DateError> for ParseExprError {
+    fn from(e: DateError) -> Self {
+        Self::DateError(e)
+    }
+}
+
+impl From<ParseOfDayError> for ParseExprError {
+    fn from(e: ParseOfDayError) -> Self {
+        Self::ParseOfDayError(e)
+    }
+}
+
+// The time at the given local date and time of day, or `None` if the date is
+// outside of the range of `Time`.
+fn date_to_time(date: Date, ofday: OfDay, tz: Tz) -> Option<Time> {
+    if Time::date_in_range(date) {
+        Some(Time::of_date_ofday_lenient(date, ofday, tz))
+    } else {
+        None
+    }
+}
+
+// Splits a token such as "now-15m" in a word and an offset.
+fn split_offset(token: &str) -> (&str, Option<&str>) {
+    match token.char_indices().skip(1).find(|(_, c)| *c == '+' || *c == '-') {
+        Some((index, _)) => (&token[..index], Some(&token[index..])),
+        None => (token, None),
+    }
+}
+
+impl DateOrTime {
+    /// The time for this value in the given timezone, dates are converted to the
+    /// start of the day. Returns `None` for dates outside of the range of `Time`,
+    /// roughly 1677 to 2262, which `parse` returns for inputs such as "9999-12-31".
+    pub fn to_time(self, tz: Tz) -> Option<Time> {
+        match self {
+            Self::Date(date) => date_to_time(date, OfDay::START_OF_DAY, tz),
+            Self::Time(time) => Some(time),
+        }
+    }
+
+    // `token` is only used for error reporting.
+    fn add_span(self, span: Span, token: &str, tz: Tz) -> Result<Self, ParseExprError> {
+        let res = match self {
+            Self::Date(date) if span % Span::DAY == Span::ZERO => {
+                let days = i32::try_from(span.to_int_ns() / Span::DAY.to_int_ns()).ok();
+                days.and_then(|days| date.checked_add(days)).map(Self::Date)
+            }
+            Self::Date(date) => date_to_time(date, OfDay::START_OF_DAY, tz)
+                .and_then(|time| time.checked_add(span))
+                .map(Self::Time),
+            Self::Time(time) => time.checked_add(span).map(Self::Time),
+        };
+        res.ok_or_else(|| ParseExprError::OutOfRange(token.to_string()))
+    }
+
+    fn add_ofday(self, token: &str, tz: Tz) -> Result<Self, ParseExprError> {
+        let date = match self {
+            Self::Date(date) => date,
+            Self::Time(_) => return Err(ParseExprError::OfDayAfterTime(token.to_string())),
+        };
+        let (ofday, tz) = match token.strip_suffix(['Z', 'z']) {
+            Some(ofday) => (ofday, Tz::GMT),
+            None => (token, tz),
+        };
+        let ofday = OfDay::from_str(ofday)?;
+        let time = date_to_time(date, ofday, tz)
+            .ok_or_else(|| ParseExprError::OutOfRange(token.to_string()))?;
+        Ok(Self::Time(time))
+    }
+
+    /// Parses an expression and resolves it against `now` and `tz`. An expression
+    /// is made of whitespace separated tokens:
+    /// - an optional base: "now", "today", "tomorrow", "yesterday", "next friday",
+    ///   "last mon", or a date such as "2021-01-16". This defaults to "now" when
+    ///   the expression starts with an offset, and to "today" when it starts with
+    ///   a time of day.
+    /// - some offsets parsed as spans with `Span::of_string_strict`, e.g. "-3d" or
+    ///   "+1h30m". Offsets can also be attached to the base, e.g. "now-15m".
+    ///   Offsets that are not a whole number of days turn a date into a time.
+    /// - an optional time of day, e.g. "16:00", that turns a date into a time. A
+    ///   trailing "Z" means that the time of day is in GMT.
+    /// - an optional timezone, e.g. "America/New_York", that replaces `tz`.
+    ///
+    /// Local times are resolved as per `Time::of_date_ofday_lenient`.
+    pub fn parse(s: &str, now: Time, tz: Tz) -> Result<Self, ParseExprError> {
+        let mut tokens: Vec<&str> = s.split_whitespace().collect();
+        if tokens.is_empty() {
+            return Err(ParseExprError::EmptyString);
+        }
+        let tz = match tokens.last().map(|t| Tz::from_str(t)) {
+            Some(Ok(tz)) => {
+                tokens.pop();
+                tz
+            }
+            _ => tz,
+        };
+        let mut tokens = tokens.into_iter().peekable();
+        let today = now.to_date(tz);
+        let (mut res, offset) = match tokens.peek().copied() {
+            None => (Self::Time(now), None),
+            Some(token) if token.starts_with(['+', '-']) || token.contains(':') => {
+                let base = if token.contains(':') { Self::Date(today) } else { Self::Time(now) };
+                (base, None)
+            }
+            Some(token) if token.starts_with(|c: char| c.is_ascii_digit()) => {
+                tokens.next();
+                (Self::Date(Date::from_str(token)?), None)
+            }
+            Some(token) => {
+                tokens.next();
+                let (word, offset) = split_offset(token);
+                let base = match word.to_ascii_lowercase().as_str() {
+                    "now" => Self::Time(now),
+                    "today" => Self::Date(today),
+                    "tomorrow" => Self::Date(today + 1),
+                    "yesterday" => Self::Date(today - 1),
+                    "next" | "last" => {
+                        let dow = tokens.next().and_then(|t| DayOfWeek::from_str(t).ok());
+                        let dow = dow.ok_or_else(|| ParseExprError::MissingDayOfWeek(s.into()))?;
+                        let date = if word.eq_ignore_ascii_case("next") {
+                            today.next_weekday(dow, Inclusivity::Exclusive)
+                        } else {
+                            today.prev_weekday(dow, Inclusivity::Exclusive)
+                        };
+                        Self::Date(date.ok_or_else(|| ParseExprError::OutOfRange(s.into()))?)
+                    }
+                    _ => return Err(ParseExprError::UnexpectedToken(token.to_string())),
+                };
+                (base, offset)
+            }
+        };
+        if let Some(offset) = offset {
+            res = res.add_span(Span::of_string_strict(offset)?, offset, tz)?
+        }
+        while let Some(token) = tokens.next() {
+            if token == "+" || token == "-" {
+                let span =
+                    tokens.next().ok_or_else(|| ParseExprError::UnexpectedToken(token.into()))?;
+                let span_token = span;
+                let span = Span::of_string_strict(span)?;
+                res = res.add_span(if token == "-" { -span } else { span }, span_token, tz)?
+            } else if token.starts_with(['+', '-']) {
+                res = res.add_span(Span::of_string_strict(token)?, token, tz)?
+            } else if token.contains(':') {
+                res = res.add_ofday(token, tz)?
+            } else {
+                return Err(ParseExprError::UnexpectedToken(token.to_string()));
+            }
+        }
+        Ok(res)
+    }
+}
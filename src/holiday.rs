@@ -0,0 +1,188 @@
+use crate::{Date, DayOfWeek, Month};
+
+/// A set of holidays, business days are the weekdays that are not holidays.
+///
+/// The predicates can be used with the business day functions of `Date`, e.g.
+/// `date.add_business_days_rounding_forward(2, |d| calendar.is_business_day(d))`.
+pub trait HolidayCalendar {
+    /// Returns true if `date` is a holiday.
+    fn is_holiday(&self, date: Date) -> bool;
+
+    /// Returns true if `date` is neither a weekend day nor a holiday.
+    fn is_business_day(&self, date: Date) -> bool {
+        date.is_weekday() && !self.is_holiday(date)
+    }
+
+    /// Lists the holidays between two dates (inclusive).
+    fn holidays_between(&self, lo: Date, up: Date) -> Vec<Date> {
+        Date::dates_between(lo, up).filter(|&d| self.is_holiday(d)).collect()
+    }
+
+    /// A calendar where a date is a holiday if it is a holiday in either calendar,
+    /// e.g. the days when either of two exchanges is closed.
+    fn union<C: HolidayCalendar>(self, other: C) -> CalendarUnion<Self, C>
+    where
+        Self: Sized,
+    {
+        CalendarUnion(self, other)
+    }
+
+    /// A calendar where a date is a holiday if it is a holiday in both calendars,
+    /// e.g. the days when both of two exchanges are closed.
+    fn intersection<C: HolidayCalendar>(self, other: C) -> CalendarIntersection<Self, C>
+    where
+        Self: Sized,
+    {
+        CalendarIntersection(self, other)
+    }
+}
+
+impl<C: HolidayCalendar + ?Sized> HolidayCalendar for &C {
+    fn is_holiday(&self, date: Date) -> bool {
+        (**self).is_holiday(date)
+    }
+}
+
+impl<C: HolidayCalendar + ?Sized> HolidayCalendar for Box<C> {
+    fn is_holiday(&self, date: Date) -> bool {
+        (**self).is_holiday(date)
+    }
+}
+
+/// The union of two calendars, see `HolidayCalendar::union`.
+#[derive(Clone, Debug)]
+pub struct CalendarUnion<A, B>(pub A, pub B);
+
+impl<A: HolidayCalendar, B: HolidayCalendar> HolidayCalendar for CalendarUnion<A, B> {
+    fn is_holiday(&self, date: Date) -> bool {
+        self.0.is_holiday(date) || self.1.is_holiday(date)
+    }
+}
+
+/// The intersection of two calendars, see `HolidayCalendar::intersection`.
+#[derive(Clone, Debug)]
+pub struct CalendarIntersection<A, B>(pub A, pub B);
+
+impl<A: HolidayCalendar, B: HolidayCalendar> HolidayCalendar for CalendarIntersection<A, B> {
+    fn is_holiday(&self, date: Date) -> bool {
+        self.0.is_holiday(date) && self.1.is_holiday(date)
+    }
+}
+
+/// How a fixed date holiday falling on a weekend is observed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Observance {
+    /// The holiday is not moved.
+    Unadjusted,
+    /// Saturday holidays are observed on Friday and Sunday holidays on Monday.
+    NearestWeekday,
+    /// Saturday and Sunday holidays are observed on the following Monday.
+    NextMonday,
+    /// Sunday holidays are observed on Monday, Saturday holidays are not moved.
+    SundayToMonday,
+}
+
+impl Observance {
+    /// The date on which a holiday falling on `date` is observed, returns `None`
+    /// if this date is out of `[Date::MIN, Date::MAX]`.
+    pub fn observed(self, date: Date) -> Option<Date> {
+        match (self, date.day_of_week()) {
+            (Self::NearestWeekday, DayOfWeek::Sat) => date.checked_sub(1),
+            (Self::NearestWeekday | Self::NextMonday | Self::SundayToMonday, DayOfWeek::Sun) => {
+                date.checked_add(1)
+            }
+            (Self::NextMonday, DayOfWeek::Sat) => date.checked_add(2),
+            _ => Some(date),
+        }
+    }
+}
+
+/// A rule generating at most one holiday per year.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HolidayRule {
+    /// The same day every year, e.g. Dec 25, possibly moved when on a weekend.
+    Fixed { month: Month, day: u8, observance: Observance },
+    /// The n-th day of the week of a month, e.g. the fourth Thursday of November.
+    /// Negative values of `n` count from the end of the month.
+    NthWeekday { month: Month, n: i32, day_of_week: DayOfWeek },
+    /// Some number of days after (or before when negative) Western Easter Sunday,
+    /// e.g. -2 for Good Friday or 1 for Easter Monday.
+    EasterOffset(i32),
+    /// Some number of days after (or before when negative) Orthodox Easter Sunday.
+    OrthodoxEasterOffset(i32),
+    /// A single date, e.g. a day of national mourning.
+    OneOff(Date),
+}
+
+impl HolidayRule {
+    /// The date of the holiday generated by this rule for `year`, if any. For
+    /// fixed date holidays, this is the observed date which may fall in the
+    /// previous or next year.
+    pub fn date_in_year(&self, year: u32) -> Option<Date> {
+        match *self {
+            Self::Fixed { month, day, observance } => {
+                Date::create(year, month, day).ok().and_then(|date| observance.observed(date))
+            }
+            Self::NthWeekday { month, n, day_of_week } => {
+                Date::create(year, month, 1).ok()?.nth_weekday_of_month(n, day_of_week)
+            }
+            Self::EasterOffset(offset) => Date::easter_western(year).ok()?.checked_add(offset),
+            Self::OrthodoxEasterOffset(offset) => {
+                Date::easter_orthodox(year).ok()?.checked_add(offset)
+            }
+            Self::OneOff(date) => {
+                if date.year() == year {
+                    Some(date)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Returns true if this rule generates a holiday on `date`.
+    pub fn is_holiday(&self, date: Date) -> bool {
+        let year = date.year();
+        let years = match (self, date.month()) {
+            // Easter always falls in its own year, so only the year of `date - offset`
+            // can generate this date however large the offset.
+            (Self::EasterOffset(offset) | Self::OrthodoxEasterOffset(offset), _) => {
+                [date.checked_sub(*offset).map(|d| d.year()), None]
+            }
+            // Weekend observance can move a holiday across the year boundary.
+            (_, Month::Jan) => [year.checked_sub(1), Some(year)],
+            (_, Month::Dec) => [Some(year), Some(year + 1)],
+            _ => [Some(year), None],
+        };
+        years.into_iter().flatten().any(|year| self.date_in_year(year) == Some(date))
+    }
+}
+
+/// A holiday calendar defined by a list of rules.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RuleCalendar {
+    rules: Vec<HolidayRule>,
+}
+
+impl RuleCalendar {
+    /// Creates a calendar from a list of rules.
+    pub fn new(rules: Vec<HolidayRule>) -> Self {
+        Self { rules }
+    }
+
+    /// Adds a rule to this calendar.
+    pub fn push(&mut self, rule: HolidayRule) {
+        self.rules.push(rule)
+    }
+
+    /// The rules for this calendar.
+    pub fn rules(&self) -> &[HolidayRule] {
+        &self.rules
+    }
+}
+
+impl HolidayCalendar for RuleCalendar {
+    fn is_holiday(&self, date: Date) -> bool {
+        self.rules.iter().any(|rule| rule.is_holiday(date))
+    }
+}
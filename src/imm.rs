@@ -0,0 +1,139 @@
+use crate::{Date, DateError, DayOfWeek, Inclusivity, Month};
+use std::str::FromStr;
+
+impl Month {
+    /// The futures month code, e.g. 'F' for January or 'Z' for December.
+    pub const fn imm_code(self) -> char {
+        match self {
+            Self::Jan => 'F',
+            Self::Feb => 'G',
+            Self::Mar => 'H',
+            Self::Apr => 'J',
+            Self::May => 'K',
+            Self::Jun => 'M',
+            Self::Jul => 'N',
+            Self::Aug => 'Q',
+            Self::Sep => 'U',
+            Self::Oct => 'V',
+            Self::Nov => 'X',
+            Self::Dec => 'Z',
+        }
+    }
+
+    /// Converts from a futures month code, the conversion is case insensitive.
+    pub const fn of_imm_code(c: char) -> Option<Self> {
+        match c.to_ascii_uppercase() {
+            'F' => Some(Self::Jan),
+            'G' => Some(Self::Feb),
+            'H' => Some(Self::Mar),
+            'J' => Some(Self::Apr),
+            'K' => Some(Self::May),
+            'M' => Some(Self::Jun),
+            'N' => Some(Self::Jul),
+            'Q' => Some(Self::Aug),
+            'U' => Some(Self::Sep),
+            'V' => Some(Self::Oct),
+            'X' => Some(Self::Nov),
+            'Z' => Some(Self::Dec),
+            _ => None,
+        }
+    }
+
+    /// Returns true for March, June, September, and December.
+    pub const fn is_imm_month(self) -> bool {
+        matches!(self, Self::Mar | Self::Jun | Self::Sep | Self::Dec)
+    }
+}
+
+impl Date {
+    /// The third Wednesday of the given month, this is an IMM date when the month
+    /// is March, June, September, or December.
+    pub fn third_wednesday(year: u32, month: Month) -> Result<Self, DateError> {
+        let first = Self::create(year, month, 1)?;
+        Ok(first.nth_weekday_of_month(3, DayOfWeek::Wed).unwrap())
+    }
+
+    /// Returns true if this date is the third Wednesday of March, June, September,
+    /// or December.
+    pub fn is_imm_date(self) -> bool {
+        self.month().is_imm_month()
+            && self.day_of_week() == DayOfWeek::Wed
+            && (15..=21).contains(&self.day())
+    }
+
+    /// The first IMM date on or after `self`, `self` is excluded from the search
+    /// when `inclusivity` is `Exclusive`. Returns `None` if there is no such date
+    /// before `Date::MAX`.
+    pub fn next_imm_date(self, inclusivity: Inclusivity) -> Option<Self> {
+        let mut date = self.first_day_of_month();
+        loop {
+            if date.month().is_imm_month() {
+                let imm_date = date.nth_weekday_of_month(3, DayOfWeek::Wed).unwrap();
+                match inclusivity {
+                    Inclusivity::Inclusive if imm_date >= self => return Some(imm_date),
+                    Inclusivity::Exclusive if imm_date > self => return Some(imm_date),
+                    _ => {}
+                }
+            }
+            if date == Self::MAX.first_day_of_month() {
+                return None;
+            }
+            date = date.add_months(1);
+        }
+    }
+
+    /// The last IMM date on or before `self`, `self` is excluded from the search
+    /// when `inclusivity` is `Exclusive`. Returns `None` if there is no such date
+    /// after `Date::MIN`.
+    pub fn prev_imm_date(self, inclusivity: Inclusivity) -> Option<Self> {
+        let mut date = self.first_day_of_month();
+        loop {
+            if date.month().is_imm_month() {
+                let imm_date = date.nth_weekday_of_month(3, DayOfWeek::Wed).unwrap();
+                match inclusivity {
+                    Inclusivity::Inclusive if imm_date <= self => return Some(imm_date),
+                    Inclusivity::Exclusive if imm_date < self => return Some(imm_date),
+                    _ => {}
+                }
+            }
+            if date == Self::MIN {
+                return None;
+            }
+            date = date.add_months(-1);
+        }
+    }
+
+    /// The IMM code for the month of this date using the last two digits of the
+    /// year, e.g. "H21" for 2021-03-17.
+    pub fn to_imm_code(self) -> String {
+        format!("{}{:02}", self.month().imm_code(), self.year() % 100)
+    }
+
+    /// The IMM code for the month of this date using the last digit of the year,
+    /// e.g. "H1" for 2021-03-17.
+    pub fn to_imm_code_short(self) -> String {
+        format!("{}{}", self.month().imm_code(), self.year() % 10)
+    }
+
+    /// The third Wednesday of the month designated by an IMM code such as "H21"
+    /// or "Z5". As the code only holds the last digits of the year, this returns
+    /// the first matching date on or after `reference`.
+    pub fn of_imm_code(code: &str, reference: Self) -> Result<Self, DateError> {
+        let mut chars = code.chars();
+        let month =
+            chars.next().and_then(Month::of_imm_code).ok_or(DateError::ParseImmCodeError)?;
+        let digits = chars.as_str();
+        if digits.is_empty() || digits.len() > 2 || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(DateError::ParseImmCodeError);
+        }
+        let modulo = 10u32.pow(digits.len() as u32);
+        let year_digits = u32::from_str(digits)?;
+        let year = reference.year() - reference.year() % modulo + year_digits;
+        let date = Self::third_wednesday(year, month)?;
+        if date >= reference {
+            Ok(date)
+        } else {
+            Self::third_wednesday(year + modulo, month)
+        }
+    }
+}
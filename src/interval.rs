@@ -0,0 +1,289 @@
+use crate::{Date, OfDay, Span, Time};
+
+/// A half-open interval [lo, hi), the interval is empty when lo == hi. For dates
+/// the upper bound is excluded too, so [2021-01-01, 2021-01-08) is one week.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    lo: T,
+    hi: T,
+}
+
+impl<T: Ord + Copy> Interval<T> {
+    /// Returns `None` if `lo` is greater than `hi`.
+    pub fn new(lo: T, hi: T) -> Option<Self> {
+        if lo <= hi {
+            Some(Self { lo, hi })
+        } else {
+            None
+        }
+    }
+
+    pub fn lo(&self) -> T {
+        self.lo
+    }
+
+    pub fn hi(&self) -> T {
+        self.hi
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lo == self.hi
+    }
+
+    pub fn contains(&self, v: T) -> bool {
+        self.lo <= v && v < self.hi
+    }
+
+    /// Whether `other` is included in this interval, empty intervals are included
+    /// in any interval.
+    pub fn contains_interval(&self, other: &Self) -> bool {
+        other.is_empty() || (self.lo <= other.lo && other.hi <= self.hi)
+    }
+
+    /// Whether the two intervals have at least one element in common.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.lo.max(other.lo) < self.hi.min(other.hi)
+    }
+
+    /// The elements in both intervals, or `None` if they do not overlap.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let lo = self.lo.max(other.lo);
+        let hi = self.hi.min(other.hi);
+        if lo < hi {
+            Some(Self { lo, hi })
+        } else {
+            None
+        }
+    }
+
+    /// The union of the two intervals if it is an interval, i.e. if they overlap
+    /// or are adjacent.
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        if self.is_empty() {
+            Some(*other)
+        } else if other.is_empty() {
+            Some(*self)
+        } else if self.lo.max(other.lo) <= self.hi.min(other.hi) {
+            Some(Self { lo: self.lo.min(other.lo), hi: self.hi.max(other.hi) })
+        } else {
+            None
+        }
+    }
+
+    /// The smallest interval containing both intervals.
+    pub fn hull(&self, other: &Self) -> Self {
+        Self { lo: self.lo.min(other.lo), hi: self.hi.max(other.hi) }
+    }
+
+    /// The interval between two non-overlapping intervals, or `None` if they
+    /// overlap or are adjacent.
+    pub fn gap(&self, other: &Self) -> Option<Self> {
+        let lo = self.hi.min(other.hi);
+        let hi = self.lo.max(other.lo);
+        if lo < hi {
+            Some(Self { lo, hi })
+        } else {
+            None
+        }
+    }
+}
+
+impl Interval<Time> {
+    /// The length of this interval, panics when it does not fit in a `Span`,
+    /// e.g. for `Interval::new(Time::MIN, Time::MAX)`, see `checked_span`.
+    pub fn span(&self) -> Span {
+        self.checked_span().expect("span overflow")
+    }
+
+    /// The length of this interval, returns `None` when it does not fit in a `Span`.
+    pub fn checked_span(&self) -> Option<Span> {
+        self.hi.checked_diff(self.lo)
+    }
+}
+
+impl Interval<OfDay> {
+    pub fn span(&self) -> Span {
+        self.hi.to_span_since_midnight() - self.lo.to_span_since_midnight()
+    }
+}
+
+impl Interval<Date> {
+    /// The number of days in the interval.
+    pub fn days(&self) -> i32 {
+        self.hi - self.lo
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}, {})", self.lo, self.hi)
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Debug for Interval<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+/// A set of elements represented as sorted, non-empty, and non-adjacent
+/// intervals.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { intervals: vec![] }
+    }
+}
+
+impl<T: Ord + Copy> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Sorts and merges overlapping or adjacent intervals.
+    fn normalize(mut intervals: Vec<Interval<T>>) -> Self {
+        intervals.retain(|i| !i.is_empty());
+        intervals.sort_by_key(|i| i.lo);
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(last) if interval.lo <= last.hi => last.hi = last.hi.max(interval.hi),
+                _ => merged.push(interval),
+            }
+        }
+        Self { intervals: merged }
+    }
+
+    /// The normalized intervals, sorted by lower bound.
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Interval<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains(&self, v: T) -> bool {
+        let index = self.intervals.partition_point(|i| i.hi <= v);
+        self.intervals.get(index).is_some_and(|i| i.contains(v))
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let mut intervals = std::mem::take(&mut self.intervals);
+        intervals.push(interval);
+        *self = Self::normalize(intervals)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalize(self.intervals.iter().chain(other.intervals.iter()).copied().collect())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            if let Some(inter) = a.intersection(b) {
+                intervals.push(inter)
+            }
+            if a.hi <= b.hi {
+                i += 1
+            } else {
+                j += 1
+            }
+        }
+        Self { intervals }
+    }
+
+    /// The elements of this set that are not in `other`.
+    pub fn subtract(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let mut j = 0;
+        for interval in self.intervals.iter() {
+            let mut lo = interval.lo;
+            // Skip the intervals from `other` that end before this one starts.
+            while other.intervals.get(j).is_some_and(|o| o.hi <= lo) {
+                j += 1
+            }
+            let mut k = j;
+            while let Some(o) = other.intervals.get(k).filter(|o| o.lo < interval.hi) {
+                if lo < o.lo {
+                    intervals.push(Interval { lo, hi: o.lo })
+                }
+                lo = lo.max(o.hi);
+                k += 1
+            }
+            if lo < interval.hi {
+                intervals.push(Interval { lo, hi: interval.hi })
+            }
+        }
+        Self { intervals }
+    }
+
+    /// The elements of `window` that are not in this set.
+    pub fn complement(&self, window: Interval<T>) -> Self {
+        Self::from(window).subtract(self)
+    }
+}
+
+impl IntervalSet<Time> {
+    /// The total time covered by the intervals of this set, panics when it does
+    /// not fit in a `Span`, see `checked_total_span`.
+    pub fn total_span(&self) -> Span {
+        self.checked_total_span().expect("span overflow")
+    }
+
+    /// The total time covered by the intervals of this set, returns `None` when
+    /// it does not fit in a `Span`.
+    pub fn checked_total_span(&self) -> Option<Span> {
+        self.intervals.iter().try_fold(Span::ZERO, |acc, i| acc.checked_add(i.checked_span()?))
+    }
+}
+
+impl IntervalSet<OfDay> {
+    /// The total time covered by the intervals of this set.
+    pub fn total_span(&self) -> Span {
+        self.intervals.iter().fold(Span::ZERO, |acc, i| acc + i.span())
+    }
+}
+
+impl IntervalSet<Date> {
+    /// The total number of days covered by the intervals of this set.
+    pub fn total_days(&self) -> i32 {
+        self.intervals.iter().map(|i| i.days()).sum()
+    }
+}
+
+impl<T: Ord + Copy> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        Self::normalize(vec![interval])
+    }
+}
+
+impl<T: Ord + Copy> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        Self::normalize(iter.into_iter().collect())
+    }
+}
+
+impl<'a, T> IntoIterator for &'a IntervalSet<T> {
+    type Item = &'a Interval<T>;
+    type IntoIter = std::slice::Iter<'a, Interval<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.iter()
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Debug for IntervalSet<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.intervals.iter()).finish()
+    }
+}
@@ -1,14 +1,22 @@
 mod date;
 pub use date::*;
 
+mod imm;
+
+mod locale;
+pub use locale::{Locale, ParseNameError};
+
 mod timezone;
 pub use timezone::{TzError, TzInfo, TzOffset, TzParseError};
 
 mod span;
-pub use span::Span;
+pub use span::{ParseIso8601SpanError, ParseSpanError, RoundingMode, Span, SpanUnit};
 
 mod ofday;
-pub use ofday::OfDay;
+pub use ofday::{OfDay, ParseOfDayError};
+
+mod period;
+pub use period::{ParsePeriodError, Period};
 
 mod timezone_data;
 pub use timezone_data::Tz;
@@ -16,6 +24,53 @@ pub use timezone_data::Tz;
 mod time;
 pub use time::*;
 
+mod step;
+pub use step::{OfDaysBetween, TimesBetween};
+
+mod std_time;
+pub use std_time::StdTimeError;
+
+mod clock;
+pub use clock::{with_clock, Clock, CoarseClock, FixedClock, ManualClock, SystemClock};
+
+mod mono_time;
+pub use mono_time::{MonoTime, MonoTimeAnchor};
+
+mod tsc_clock;
+pub use tsc_clock::{HardwareTscSource, TscClock, TscClockError, TscSource};
+
+mod interval;
+pub use interval::{Interval, IntervalSet};
+
+mod truncate;
+pub use truncate::CalendarUnit;
+
+mod day_count;
+pub use day_count::DayCount;
+
+mod schedule;
+pub use schedule::{BusinessDayConvention, RollRule, Schedule, ScheduleError};
+
+mod date_time;
+pub use date_time::{DateTime, ParseDateTimeError};
+
+mod zoned_time;
+pub use zoned_time::{FixedOffset, Zone, ZonedTime};
+
+mod zoned_ofday;
+pub use zoned_ofday::ZonedOfDay;
+
+mod expr;
+pub use expr::{DateOrTime, ParseExprError};
+
+mod relative;
+pub use relative::{RelativeFormatter, RelativeUnit};
+
+mod holiday;
+pub use holiday::{
+    CalendarIntersection, CalendarUnion, HolidayCalendar, HolidayRule, Observance, RuleCalendar,
+};
+
 #[cfg(feature = "with_chrono")]
 use ::chrono;
 #[cfg(feature = "with_chrono")]
@@ -0,0 +1,309 @@
+use crate::{Date, DayOfWeek, Month};
+
+/// The languages supported for month and day of week names.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Locale {
+    En,
+    Fr,
+    De,
+    Es,
+    It,
+    Ja,
+    Zh,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseNameError {
+    UnknownMonth(String),
+    UnknownDayOfWeek(String),
+    UnknownLocale(String),
+}
+
+impl std::fmt::Display for ParseNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl std::error::Error for ParseNameError {}
+
+// The tables below are indexed by locale, then by month starting from January
+// or by day of week starting from Sunday.
+const MONTHS: [[&str; 12]; 7] = [
+    [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    [
+        "janvier",
+        "février",
+        "mars",
+        "avril",
+        "mai",
+        "juin",
+        "juillet",
+        "août",
+        "septembre",
+        "octobre",
+        "novembre",
+        "décembre",
+    ],
+    [
+        "Januar",
+        "Februar",
+        "März",
+        "April",
+        "Mai",
+        "Juni",
+        "Juli",
+        "August",
+        "September",
+        "Oktober",
+        "November",
+        "Dezember",
+    ],
+    [
+        "enero",
+        "febrero",
+        "marzo",
+        "abril",
+        "mayo",
+        "junio",
+        "julio",
+        "agosto",
+        "septiembre",
+        "octubre",
+        "noviembre",
+        "diciembre",
+    ],
+    [
+        "gennaio",
+        "febbraio",
+        "marzo",
+        "aprile",
+        "maggio",
+        "giugno",
+        "luglio",
+        "agosto",
+        "settembre",
+        "ottobre",
+        "novembre",
+        "dicembre",
+    ],
+    ["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"],
+    [
+        "一月",
+        "二月",
+        "三月",
+        "四月",
+        "五月",
+        "六月",
+        "七月",
+        "八月",
+        "九月",
+        "十月",
+        "十一月",
+        "十二月",
+    ],
+];
+
+const MONTHS_SHORT: [[&str; 12]; 7] = [
+    ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"],
+    [
+        "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.",
+        "déc.",
+    ],
+    [
+        "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.",
+        "Dez.",
+    ],
+    ["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"],
+    ["gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic"],
+    ["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"],
+    ["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"],
+];
+
+const DAYS: [[&str; 7]; 7] = [
+    ["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"],
+    ["dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi"],
+    ["Sonntag", "Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag"],
+    ["domingo", "lunes", "martes", "miércoles", "jueves", "viernes", "sábado"],
+    ["domenica", "lunedì", "martedì", "mercoledì", "giovedì", "venerdì", "sabato"],
+    ["日曜日", "月曜日", "火曜日", "水曜日", "木曜日", "金曜日", "土曜日"],
+    ["星期日", "星期一", "星期二", "星期三", "星期四", "星期五", "星期六"],
+];
+
+const DAYS_SHORT: [[&str; 7]; 7] = [
+    ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+    ["dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam."],
+    ["So.", "Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa."],
+    ["dom", "lun", "mar", "mié", "jue", "vie", "sáb"],
+    ["dom", "lun", "mar", "mer", "gio", "ven", "sab"],
+    ["日", "月", "火", "水", "木", "金", "土"],
+    ["周日", "周一", "周二", "周三", "周四", "周五", "周六"],
+];
+
+// Case insensitive comparison, abbreviations can be used with or without
+// their trailing dot.
+fn matches_name(s: &str, name: &str) -> bool {
+    let s = s.to_lowercase();
+    let name = name.to_lowercase();
+    s == name || name.strip_suffix('.') == Some(s.as_str())
+}
+
+impl Locale {
+    pub const ALL: [Self; 7] =
+        [Self::En, Self::Fr, Self::De, Self::Es, Self::It, Self::Ja, Self::Zh];
+
+    /// The two letter ISO 639-1 code for this language, e.g. "fr".
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::En => "en",
+            Self::Fr => "fr",
+            Self::De => "de",
+            Self::Es => "es",
+            Self::It => "it",
+            Self::Ja => "ja",
+            Self::Zh => "zh",
+        }
+    }
+
+    pub const fn month_name(self, month: Month) -> &'static str {
+        MONTHS[self as usize][month.to_u8() as usize - 1]
+    }
+
+    pub const fn month_short_name(self, month: Month) -> &'static str {
+        MONTHS_SHORT[self as usize][month.to_u8() as usize - 1]
+    }
+
+    pub const fn day_of_week_name(self, day_of_week: DayOfWeek) -> &'static str {
+        DAYS[self as usize][day_of_week.to_u8() as usize]
+    }
+
+    pub const fn day_of_week_short_name(self, day_of_week: DayOfWeek) -> &'static str {
+        DAYS_SHORT[self as usize][day_of_week.to_u8() as usize]
+    }
+
+    /// Parses a full or abbreviated month name in this language, the comparison
+    /// is case insensitive.
+    pub fn parse_month(self, s: &str) -> Result<Month, ParseNameError> {
+        (1..=12)
+            .filter_map(Month::of_u8)
+            .find(|&m| {
+                matches_name(s, self.month_name(m)) || matches_name(s, self.month_short_name(m))
+            })
+            .ok_or_else(|| ParseNameError::UnknownMonth(s.to_string()))
+    }
+
+    /// Parses a full or abbreviated day of week name in this language, the
+    /// comparison is case insensitive.
+    pub fn parse_day_of_week(self, s: &str) -> Result<DayOfWeek, ParseNameError> {
+        (0..7)
+            .filter_map(DayOfWeek::of_u8)
+            .find(|&d| {
+                matches_name(s, self.day_of_week_name(d))
+                    || matches_name(s, self.day_of_week_short_name(d))
+            })
+            .ok_or_else(|| ParseNameError::UnknownDayOfWeek(s.to_string()))
+    }
+}
+
+impl std::fmt::Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl std::str::FromStr for Locale {
+    type Err = ParseNameError;
+
+    // Region subtags are ignored, e.g. "fr-CA" or "zh_TW" are accepted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let language = s.split(['-', '_']).next().unwrap_or(s);
+        Self::ALL
+            .into_iter()
+            .find(|l| l.code().eq_ignore_ascii_case(language))
+            .ok_or_else(|| ParseNameError::UnknownLocale(s.to_string()))
+    }
+}
+
+impl Month {
+    /// The full English name, e.g. "January".
+    pub const fn name(self) -> &'static str {
+        Locale::En.month_name(self)
+    }
+
+    /// The abbreviated English name, e.g. "Jan".
+    pub const fn short_name(self) -> &'static str {
+        Locale::En.month_short_name(self)
+    }
+}
+
+impl std::fmt::Display for Month {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl std::str::FromStr for Month {
+    type Err = ParseNameError;
+
+    // Accepts full and abbreviated English names, case insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Locale::En.parse_month(s)
+    }
+}
+
+impl DayOfWeek {
+    /// The full English name, e.g. "Monday".
+    pub const fn name(self) -> &'static str {
+        Locale::En.day_of_week_name(self)
+    }
+
+    /// The abbreviated English name, e.g. "Mon".
+    pub const fn short_name(self) -> &'static str {
+        Locale::En.day_of_week_short_name(self)
+    }
+}
+
+impl std::fmt::Display for DayOfWeek {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl std::str::FromStr for DayOfWeek {
+    type Err = ParseNameError;
+
+    // Accepts full and abbreviated English names, case insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Locale::En.parse_day_of_week(s)
+    }
+}
+
+impl Date {
+    /// A long representation of this date in the given language, e.g.
+    /// "Saturday, January 16, 2021" in English or "samedi 16 janvier 2021" in French.
+    pub fn to_string_long(self, locale: Locale) -> String {
+        let dow = locale.day_of_week_name(self.day_of_week());
+        let month = locale.month_name(self.month());
+        let (y, d) = (self.year(), self.day());
+        match locale {
+            Locale::En => format!("{dow}, {month} {d}, {y}"),
+            Locale::Fr | Locale::It => format!("{dow} {d} {month} {y}"),
+            Locale::De => format!("{dow}, {d}. {month} {y}"),
+            Locale::Es => format!("{dow}, {d} de {month} de {y}"),
+            Locale::Ja | Locale::Zh => format!("{y}年{}月{d}日{dow}", self.month_int()),
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{Span, Time};
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+/// A number of nanoseconds since an unspecified origin, e.g. the system boot.
+/// Values are only comparable within the same process and for the same source.
+/// Contrary to `Time` this is not affected by changes to the wall clock, e.g.
+/// when NTP steps it, so this should be used to measure latencies.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MonoTime(i64);
+
+#[cfg(unix)]
+// The casts are needed on targets where `time_t` or `c_long` are 32 bits.
+#[allow(clippy::unnecessary_cast)]
+pub(crate) fn clock_gettime_ns(clock_id: libc::clockid_t) -> i64 {
+    let mut ts = libc::timespec { tv_sec: 0, tv_nsec: 0 };
+    // This can only fail for invalid clock ids or pointers.
+    let res = unsafe { libc::clock_gettime(clock_id, &mut ts) };
+    assert_eq!(res, 0, "clock_gettime failed");
+    ts.tv_sec as i64 * 1_000_000_000 + ts.tv_nsec as i64
+}
+
+#[cfg(not(unix))]
+fn instant_ns() -> i64 {
+    static ORIGIN: std::sync::OnceLock<std::time::Instant> = std::sync::OnceLock::new();
+    ORIGIN.get_or_init(std::time::Instant::now).elapsed().as_nanos() as i64
+}
+
+impl MonoTime {
+    /// The current monotonic time, this uses CLOCK_MONOTONIC on unix systems.
+    pub fn now() -> Self {
+        #[cfg(unix)]
+        return Self(clock_gettime_ns(libc::CLOCK_MONOTONIC));
+        #[cfg(not(unix))]
+        return Self(instant_ns());
+    }
+
+    /// The current monotonic time using CLOCK_MONOTONIC_RAW on Linux, this is not
+    /// subject to NTP frequency adjustments. Values returned by this function
+    /// should not be compared to the ones returned by `now`. This is the same as
+    /// `now` on other systems.
+    pub fn now_raw() -> Self {
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        return Self(clock_gettime_ns(libc::CLOCK_MONOTONIC_RAW));
+        #[cfg(not(any(target_os = "linux", target_os = "android")))]
+        return Self::now();
+    }
+
+    pub const fn of_int_ns(ns: i64) -> Self {
+        Self(ns)
+    }
+
+    pub const fn to_int_ns(self) -> i64 {
+        self.0
+    }
+
+    /// The span elapsed since `self`, using `now` as the current time.
+    pub fn elapsed(self) -> Span {
+        Self::now() - self
+    }
+}
+
+impl std::fmt::Debug for MonoTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MonoTime({})", Span::of_int_ns(self.0))
+    }
+}
+
+impl Add<Span> for MonoTime {
+    type Output = Self;
+
+    fn add(self, other: Span) -> Self {
+        Self(self.0 + other.to_int_ns())
+    }
+}
+
+impl AddAssign<Span> for MonoTime {
+    fn add_assign(&mut self, other: Span) {
+        self.0 += other.to_int_ns()
+    }
+}
+
+impl Sub<Span> for MonoTime {
+    type Output = Self;
+
+    fn sub(self, other: Span) -> Self {
+        Self(self.0 - other.to_int_ns())
+    }
+}
+
+impl SubAssign<Span> for MonoTime {
+    fn sub_assign(&mut self, other: Span) {
+        self.0 -= other.to_int_ns()
+    }
+}
+
+impl Sub for MonoTime {
+    type Output = Span;
+
+    fn sub(self, other: Self) -> Span {
+        Span::of_int_ns(self.0 - other.0)
+    }
+}
+
+/// A pair of a monotonic time and a wall clock time taken at the same instant,
+/// used to convert between the two, e.g. so that log lines can carry both.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MonoTimeAnchor {
+    mono: MonoTime,
+    time: Time,
+}
+
+impl MonoTimeAnchor {
+    pub const fn create(mono: MonoTime, time: Time) -> Self {
+        Self { mono, time }
+    }
+
+    /// Samples both clocks, the monotonic time is the midpoint of two samples
+    /// taken before and after `Time::now`.
+    pub fn now() -> Self {
+        let before = MonoTime::now();
+        let time = Time::now();
+        let after = MonoTime::now();
+        Self { mono: before + (after - before) / 2, time }
+    }
+
+    pub const fn mono(&self) -> MonoTime {
+        self.mono
+    }
+
+    pub const fn time(&self) -> Time {
+        self.time
+    }
+
+    /// The wall clock time corresponding to `mono`, assuming that the wall clock
+    /// has not been adjusted since this anchor was taken.
+    pub fn to_time(&self, mono: MonoTime) -> Time {
+        self.time + (mono - self.mono)
+    }
+
+    /// The monotonic time corresponding to `time`, assuming that the wall clock
+    /// has not been adjusted since this anchor was taken.
+    pub fn to_mono(&self, time: Time) -> MonoTime {
+        self.mono + (time - self.time)
+    }
+}
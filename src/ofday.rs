@@ -19,7 +19,7 @@ impl Add<Span> for OfDay {
     type Output = Self;
 
     fn add(self, other: Span) -> Self {
-        Self(self.0 + other.to_int_ns()).clamp(Self::START_OF_DAY, Self::START_OF_NEXT_DAY)
+        self.saturating_add(other)
     }
 }
 
@@ -33,7 +33,7 @@ impl Sub<Span> for OfDay {
     type Output = Self;
 
     fn sub(self, other: Span) -> Self {
-        Self(self.0 - other.to_int_ns()).clamp(Self::START_OF_DAY, Self::START_OF_NEXT_DAY)
+        self.saturating_sub(other)
     }
 }
 
@@ -76,6 +76,34 @@ impl OfDay {
         Self(i).clamp(Self::START_OF_DAY, Self::START_OF_NEXT_DAY)
     }
 
+    /// Returns `None` if the result is before the start of the day or after the
+    /// start of the next day.
+    pub fn checked_add(self, span: Span) -> Option<Self> {
+        self.0
+            .checked_add(span.to_int_ns())
+            .filter(|ns| (Self::START_OF_DAY.0..=Self::START_OF_NEXT_DAY.0).contains(ns))
+            .map(Self)
+    }
+
+    /// Returns `None` if the result is before the start of the day or after the
+    /// start of the next day.
+    pub fn checked_sub(self, span: Span) -> Option<Self> {
+        self.0
+            .checked_sub(span.to_int_ns())
+            .filter(|ns| (Self::START_OF_DAY.0..=Self::START_OF_NEXT_DAY.0).contains(ns))
+            .map(Self)
+    }
+
+    /// The result is clamped between the start of the day and the start of the next day.
+    pub fn saturating_add(self, span: Span) -> Self {
+        Self::of_ns_since_midnight(self.0.saturating_add(span.to_int_ns()))
+    }
+
+    /// The result is clamped between the start of the day and the start of the next day.
+    pub fn saturating_sub(self, span: Span) -> Self {
+        Self::of_ns_since_midnight(self.0.saturating_sub(span.to_int_ns()))
+    }
+
     /// The number of nanoseconds since midnight.
     pub const fn to_ns_since_midnight(self) -> i64 {
         self.0
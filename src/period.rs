@@ -0,0 +1,417 @@
+use crate::{Date, Time, Tz};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A calendar period made of some years, months, weeks, and days, e.g. "1Y6M".
+/// Contrary to `Span`, a period does not have a fixed length: adding one month
+/// to a date results in the same day of the next month.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Period {
+    years: i32,
+    months: i32,
+    weeks: i32,
+    days: i32,
+}
+
+impl Period {
+    pub const ZERO: Self = Self { years: 0, months: 0, weeks: 0, days: 0 };
+
+    /// Creates a period based on a number of years, months, weeks, and days.
+    pub const fn create(years: i32, months: i32, weeks: i32, days: i32) -> Self {
+        Self { years, months, weeks, days }
+    }
+
+    pub const fn of_years(years: i32) -> Self {
+        Self::create(years, 0, 0, 0)
+    }
+
+    pub const fn of_months(months: i32) -> Self {
+        Self::create(0, months, 0, 0)
+    }
+
+    pub const fn of_weeks(weeks: i32) -> Self {
+        Self::create(0, 0, weeks, 0)
+    }
+
+    pub const fn of_days(days: i32) -> Self {
+        Self::create(0, 0, 0, days)
+    }
+
+    pub const fn years(self) -> i32 {
+        self.years
+    }
+
+    pub const fn months(self) -> i32 {
+        self.months
+    }
+
+    pub const fn weeks(self) -> i32 {
+        self.weeks
+    }
+
+    pub const fn days(self) -> i32 {
+        self.days
+    }
+
+    /// The total number of months in this period, i.e. months plus 12 times years.
+    /// This panics if the total does not fit in an `i32`.
+    pub const fn total_months(self) -> i32 {
+        match self.checked_total_months() {
+            Some(months) => months,
+            None => panic!("period overflow"),
+        }
+    }
+
+    /// Returns `None` if the total number of months does not fit in an `i32`.
+    pub const fn checked_total_months(self) -> Option<i32> {
+        match self.years.checked_mul(12) {
+            Some(months) => months.checked_add(self.months),
+            None => None,
+        }
+    }
+
+    /// The total number of days in this period, i.e. days plus 7 times weeks.
+    /// This panics if the total does not fit in an `i32`.
+    pub const fn total_days(self) -> i32 {
+        match self.checked_total_days() {
+            Some(days) => days,
+            None => panic!("period overflow"),
+        }
+    }
+
+    /// Returns `None` if the total number of days does not fit in an `i32`.
+    pub const fn checked_total_days(self) -> Option<i32> {
+        match self.weeks.checked_mul(7) {
+            Some(days) => days.checked_add(self.days),
+            None => None,
+        }
+    }
+
+    /// Returns `None` if a component overflows, i.e. if it is `i32::MIN`.
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self::create(
+            self.years.checked_neg()?,
+            self.months.checked_neg()?,
+            self.weeks.checked_neg()?,
+            self.days.checked_neg()?,
+        ))
+    }
+
+    /// Adds two periods component-wise, returns `None` if a component overflows.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        Some(Self::create(
+            self.years.checked_add(other.years)?,
+            self.months.checked_add(other.months)?,
+            self.weeks.checked_add(other.weeks)?,
+            self.days.checked_add(other.days)?,
+        ))
+    }
+
+    /// Subtracts two periods component-wise, returns `None` if a component overflows.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        Some(Self::create(
+            self.years.checked_sub(other.years)?,
+            self.months.checked_sub(other.months)?,
+            self.weeks.checked_sub(other.weeks)?,
+            self.days.checked_sub(other.days)?,
+        ))
+    }
+
+    /// Multiplies all the components, returns `None` if a component overflows.
+    pub fn checked_mul(self, rhs: i32) -> Option<Self> {
+        Some(Self::create(
+            self.years.checked_mul(rhs)?,
+            self.months.checked_mul(rhs)?,
+            self.weeks.checked_mul(rhs)?,
+            self.days.checked_mul(rhs)?,
+        ))
+    }
+
+    pub const fn is_zero(self) -> bool {
+        self.years == 0 && self.months == 0 && self.weeks == 0 && self.days == 0
+    }
+
+    /// String representation using ISO 8601, e.g. "P1Y6M" or "-P2W".
+    pub fn to_string_iso8601(self) -> String {
+        if self.is_zero() {
+            return "P0D".to_string();
+        }
+        if self.is_non_positive() {
+            format!("-P{}", self.components(true))
+        } else {
+            format!("P{}", self.components(false))
+        }
+    }
+
+    fn is_non_positive(self) -> bool {
+        self.years <= 0 && self.months <= 0 && self.weeks <= 0 && self.days <= 0
+    }
+
+    // The non-zero components, negated when `negate` is set. The absolute values
+    // are used in this case as negating `i32::MIN` would overflow.
+    fn components(self, negate: bool) -> String {
+        let mut s = String::new();
+        for (value, unit) in
+            [(self.years, 'Y'), (self.months, 'M'), (self.weeks, 'W'), (self.days, 'D')]
+        {
+            if value != 0 && negate {
+                s.push_str(&format!("{}{unit}", value.unsigned_abs()))
+            } else if value != 0 {
+                s.push_str(&format!("{value}{unit}"))
+            }
+        }
+        s
+    }
+}
+
+impl std::fmt::Display for Period {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            write!(f, "0D")
+        } else if self.is_non_positive() {
+            write!(f, "-{}", self.components(true))
+        } else {
+            write!(f, "{}", self.components(false))
+        }
+    }
+}
+
+impl std::fmt::Debug for Period {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParsePeriodError {
+    EmptyString,
+    UnexpectedChar(String, char),
+    MissingNumber(String, char),
+    MissingUnit(String),
+    DuplicateUnit(String, char),
+    TimeComponent(String),
+    // Negating the period overflows, e.g. "-P-2147483648D".
+    Overflow(String),
+    ParseIntError(std::num::ParseIntError),
+}
+
+impl std::fmt::Display for ParsePeriodError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl std::error::Error for ParsePeriodError {}
+
+impl From<std::num::ParseIntError> for ParsePeriodError {
+    fn from(e: std::num::ParseIntError) -> Self {
+        Self::ParseIntError(e)
+    }
+}
+
+impl std::str::FromStr for Period {
+    type Err = ParsePeriodError;
+
+    // Accepts both the compact format, e.g. "1Y6M", and ISO 8601, e.g. "P1Y6M".
+    // Units are case insensitive and each component can have its own sign, the
+    // first component cannot have a sign when the whole period has one.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, body) = match s.strip_prefix('-') {
+            Some(body) => (true, body),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let has_sign = body.len() < s.len();
+        let body = body.strip_prefix(['P', 'p']).unwrap_or(body);
+        if body.is_empty() {
+            return Err(ParsePeriodError::EmptyString);
+        }
+        if let Some(c) = body.chars().next().filter(|c| has_sign && (*c == '-' || *c == '+')) {
+            return Err(ParsePeriodError::UnexpectedChar(s.to_string(), c));
+        }
+        if body.contains(['T', 't']) {
+            return Err(ParsePeriodError::TimeComponent(s.to_string()));
+        }
+        let mut res = Period::ZERO;
+        let mut seen = String::new();
+        let mut number = String::new();
+        for c in body.chars() {
+            if c.is_ascii_digit() || (c == '-' && number.is_empty()) {
+                number.push(c);
+                continue;
+            }
+            let unit = c.to_ascii_uppercase();
+            let field = match unit {
+                'Y' => &mut res.years,
+                'M' => &mut res.months,
+                'W' => &mut res.weeks,
+                'D' => &mut res.days,
+                _ => return Err(ParsePeriodError::UnexpectedChar(s.to_string(), c)),
+            };
+            if number.is_empty() || number == "-" {
+                return Err(ParsePeriodError::MissingNumber(s.to_string(), c));
+            }
+            if seen.contains(unit) {
+                return Err(ParsePeriodError::DuplicateUnit(s.to_string(), c));
+            }
+            seen.push(unit);
+            *field = number.parse()?;
+            number.clear();
+        }
+        if !number.is_empty() {
+            return Err(ParsePeriodError::MissingUnit(s.to_string()));
+        }
+        if negative {
+            res.checked_neg().ok_or_else(|| ParsePeriodError::Overflow(s.to_string()))
+        } else {
+            Ok(res)
+        }
+    }
+}
+
+// The arithmetic operators panic when a component overflows, the `checked_*`
+// methods can be used instead.
+impl Neg for Period {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.checked_neg().expect("period overflow")
+    }
+}
+
+impl Add for Period {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.checked_add(other).expect("period overflow")
+    }
+}
+
+impl Sub for Period {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.checked_sub(other).expect("period overflow")
+    }
+}
+
+impl Mul<i32> for Period {
+    type Output = Self;
+
+    fn mul(self, other: i32) -> Self {
+        self.checked_mul(other).expect("period overflow")
+    }
+}
+
+impl Date {
+    /// Adds a period to this date, the years and months are added first using
+    /// `add_months` and then the weeks and days. This panics if the resulting date
+    /// is out of the 0000..=9999 year range, see `checked_add_period`.
+    pub fn add_period(self, period: Period) -> Self {
+        self.checked_add_period(period).expect("date out of range")
+    }
+
+    /// Similar to `add_period` but returns `None` if the resulting date is out of
+    /// `[Date::MIN, Date::MAX]`.
+    pub fn checked_add_period(self, period: Period) -> Option<Self> {
+        let months = period.checked_total_months()?;
+        let days = period.checked_total_days()?;
+        // `add_months` panics when the target month is out of range.
+        let target = self.year() as i64 * 12 + self.month_int() as i64 - 1 + months as i64;
+        if target < 0 || target >= (Self::MAX.year() as i64 + 1) * 12 {
+            return None;
+        }
+        self.add_months(months).checked_add(days)
+    }
+
+    /// The difference between two dates as a period of years, months, and days,
+    /// so that `a + Date::diff_period(a, b) == b`. All the components have the
+    /// same sign, e.g. from 2021-01-31 to 2021-03-01 is "1M1D".
+    pub fn diff_period(a: Self, b: Self) -> Period {
+        let mut months =
+            (b.year() as i32 - a.year() as i32) * 12 + b.month_int() as i32 - a.month_int() as i32;
+        if b >= a && a.add_months(months) > b {
+            months -= 1
+        } else if b < a && a.add_months(months) < b {
+            months += 1
+        }
+        let days = b - a.add_months(months);
+        Period::create(months / 12, months % 12, 0, days)
+    }
+}
+
+/// This panics if the resulting date is out of the 0000..=9999 year range, see
+/// `Date::checked_add_period`.
+impl Add<Period> for Date {
+    type Output = Self;
+
+    fn add(self, other: Period) -> Self {
+        self.add_period(other)
+    }
+}
+
+impl AddAssign<Period> for Date {
+    fn add_assign(&mut self, other: Period) {
+        *self = *self + other
+    }
+}
+
+/// This panics if the resulting date is out of the 0000..=9999 year range.
+impl Sub<Period> for Date {
+    type Output = Self;
+
+    fn sub(self, other: Period) -> Self {
+        let other = other.checked_neg().expect("date out of range");
+        self.add_period(other)
+    }
+}
+
+impl SubAssign<Period> for Date {
+    fn sub_assign(&mut self, other: Period) {
+        *self = *self - other
+    }
+}
+
+impl Time {
+    /// Adds a period to the local date of this time in the given timezone, keeping
+    /// the same local time of day. If this local time does not exist or is ambiguous
+    /// on the resulting date, this is resolved as per `Time::of_date_ofday_lenient`.
+    /// This panics if the resulting date is out of the range of `Time`, see
+    /// `checked_add_period`.
+    pub fn add_period(self, period: Period, tz: Tz) -> Self {
+        self.checked_add_period(period, tz).expect("time out of range")
+    }
+
+    /// Similar to `add_period` but returns `None` if the resulting date is out of
+    /// the range of `Time`.
+    pub fn checked_add_period(self, period: Period, tz: Tz) -> Option<Self> {
+        let (date, ofday) = self.to_date_ofday(tz);
+        let date = date.checked_add_period(period)?;
+        if Self::date_in_range(date) {
+            Some(Self::of_date_ofday_lenient(date, ofday, tz))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "sexp")]
+impl rsexp::UseToString for Period {}
+
+#[cfg(feature = "with_serde")]
+mod with_serde {
+    use super::Period;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::str::FromStr;
+
+    impl Serialize for Period {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.to_string().serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Period {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let s = String::deserialize(deserializer)?;
+            Period::from_str(&s).map_err(serde::de::Error::custom)
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{Date, Span, Time, Tz};
+
+/// The units used in relative descriptions. Months and years are approximated
+/// as 30 and 365 days.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RelativeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl RelativeUnit {
+    const ALL: [Self; 7] =
+        [Self::Second, Self::Minute, Self::Hour, Self::Day, Self::Week, Self::Month, Self::Year];
+
+    pub const fn span(self) -> Span {
+        match self {
+            Self::Second => Span::SEC,
+            Self::Minute => Span::MIN,
+            Self::Hour => Span::HR,
+            Self::Day => Span::DAY,
+            Self::Week => Span::of_int_day(7),
+            Self::Month => Span::of_int_day(30),
+            Self::Year => Span::of_int_day(365),
+        }
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Second => "second",
+            Self::Minute => "minute",
+            Self::Hour => "hour",
+            Self::Day => "day",
+            Self::Week => "week",
+            Self::Month => "month",
+            Self::Year => "year",
+        }
+    }
+}
+
+fn plural(n: i64, name: &str) -> String {
+    if n == 1 {
+        format!("1 {name}")
+    } else {
+        format!("{n} {name}s")
+    }
+}
+
+fn relative(n: i64, name: &str) -> String {
+    if n < 0 {
+        format!("{} ago", plural(-n, name))
+    } else {
+        format!("in {}", plural(n, name))
+    }
+}
+
+/// Formats spans and times relative to a reference, e.g. "3 minutes ago".
+/// The span is rounded to the nearest whole number of the smallest unit such
+/// that the result is below the threshold for this unit, starting from the
+/// granularity unit. Spans that round to zero are described as "just now".
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RelativeFormatter {
+    granularity: RelativeUnit,
+    // The thresholds for all the units but years, indexed by unit.
+    thresholds: [i64; 6],
+}
+
+impl Default for RelativeFormatter {
+    fn default() -> Self {
+        Self { granularity: RelativeUnit::Second, thresholds: [45, 45, 22, 7, 4, 11] }
+    }
+}
+
+impl RelativeFormatter {
+    /// A formatter using seconds as granularity, and switching to minutes at 45
+    /// seconds, to hours at 45 minutes, to days at 22 hours, to weeks at 7 days,
+    /// to months at 4 weeks, and to years at 11 months.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the smallest unit used in descriptions.
+    pub fn with_granularity(mut self, granularity: RelativeUnit) -> Self {
+        self.granularity = granularity;
+        self
+    }
+
+    /// Sets the number of `unit` from which the next unit is used, this has no
+    /// effect for years as this is the largest unit.
+    pub fn with_threshold(mut self, unit: RelativeUnit, threshold: u32) -> Self {
+        if let Some(t) = self.thresholds.get_mut(unit as usize) {
+            *t = threshold as i64
+        }
+        self
+    }
+
+    pub fn granularity(&self) -> RelativeUnit {
+        self.granularity
+    }
+
+    /// Describes a span from now, positive spans are in the future, e.g. "in 2 days",
+    /// and negative spans in the past, e.g. "3 minutes ago".
+    pub fn format_span(&self, span: Span) -> String {
+        let abs = span.to_int_ns().unsigned_abs() as i128;
+        for unit in RelativeUnit::ALL.into_iter().skip(self.granularity as usize) {
+            let unit_ns = unit.span().to_int_ns() as i128;
+            let n = ((2 * abs + unit_ns) / (2 * unit_ns)) as i64;
+            let below_threshold = self.thresholds.get(unit as usize).is_none_or(|t| n < *t);
+            if below_threshold || unit == RelativeUnit::Year {
+                if n == 0 {
+                    return "just now".to_string();
+                }
+                return relative(if span.is_negative() { -n } else { n }, unit.name());
+            }
+        }
+        unreachable!()
+    }
+
+    /// Describes `time` relative to `now`, e.g. "3 minutes ago" or "in 2 days".
+    pub fn format_time(&self, time: Time, now: Time) -> String {
+        self.format_span(time - now)
+    }
+
+    /// Describes `date` relative to `today` using calendar terms, the number of
+    /// days, Monday-based weeks, calendar months, or years between the two dates
+    /// is compared to the thresholds the same way as in `format_span`, and the
+    /// granularity is at least a day. Days are described as "today", "tomorrow",
+    /// "yesterday", "last Friday" or "next Friday" within a week, other units as
+    /// e.g. "this week", "next month", or "3 years ago".
+    pub fn format_date(&self, date: Date, today: Date) -> String {
+        // The index of the Monday starting the week of a date.
+        let monday = |d: Date| (d - Date::MIN) - (d.day_of_week().to_iso_u8() as i32 - 1);
+        let years = date.year() as i64 - today.year() as i64;
+        let counts = [
+            (RelativeUnit::Day, (date - today) as i64),
+            (RelativeUnit::Week, ((monday(date) - monday(today)) / 7) as i64),
+            (RelativeUnit::Month, years * 12 + date.month_int() as i64 - today.month_int() as i64),
+            (RelativeUnit::Year, years),
+        ];
+        let granularity = self.granularity.max(RelativeUnit::Day);
+        for (unit, n) in counts.into_iter().filter(|(unit, _)| *unit >= granularity) {
+            let below_threshold = self.thresholds.get(unit as usize).is_none_or(|t| n.abs() < *t);
+            if below_threshold || unit == RelativeUnit::Year {
+                return match (unit, n) {
+                    (RelativeUnit::Day, 0) => "today".to_string(),
+                    (RelativeUnit::Day, 1) => "tomorrow".to_string(),
+                    (RelativeUnit::Day, -1) => "yesterday".to_string(),
+                    (RelativeUnit::Day, 2..=6) => format!("next {}", date.day_of_week()),
+                    (RelativeUnit::Day, -6..=-2) => format!("last {}", date.day_of_week()),
+                    (_, 0) => format!("this {}", unit.name()),
+                    (RelativeUnit::Week | RelativeUnit::Month | RelativeUnit::Year, 1) => {
+                        format!("next {}", unit.name())
+                    }
+                    (RelativeUnit::Week | RelativeUnit::Month | RelativeUnit::Year, -1) => {
+                        format!("last {}", unit.name())
+                    }
+                    (_, n) => relative(n, unit.name()),
+                };
+            }
+        }
+        unreachable!()
+    }
+
+    /// Describes the local date of `time` relative to the local date of `now`
+    /// in the given timezone, see `format_date`.
+    pub fn format_time_calendar(&self, time: Time, now: Time, tz: Tz) -> String {
+        self.format_date(time.to_date(tz), now.to_date(tz))
+    }
+}
+
+impl Span {
+    /// An approximate description of this span from now using the default
+    /// `RelativeFormatter`, e.g. "in 2 days" or "3 minutes ago".
+    pub fn to_string_relative(self) -> String {
+        RelativeFormatter::default().format_span(self)
+    }
+}
+
+impl Time {
+    /// An approximate description of this time relative to `now` using the default
+    /// `RelativeFormatter`, e.g. "in 2 days" or "3 minutes ago".
+    pub fn to_string_relative(self, now: Time) -> String {
+        RelativeFormatter::default().format_time(self, now)
+    }
+}
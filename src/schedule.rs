@@ -0,0 +1,173 @@
+use crate::{Date, DayOfWeek};
+
+/// How a date falling on a non-business day is adjusted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BusinessDayConvention {
+    /// The date is not adjusted.
+    Unadjusted,
+    /// The first business day after the date.
+    Following,
+    /// The first business day after the date, unless it falls in the next month
+    /// in which case the last business day before the date is used.
+    ModifiedFollowing,
+    /// The last business day before the date.
+    Preceding,
+    /// The last business day before the date, unless it falls in the previous
+    /// month in which case the first business day after the date is used.
+    ModifiedPreceding,
+}
+
+// The first business day starting from `date` and going by `step`, or `None` if
+// there is none in `[Date::MIN, Date::MAX]`.
+fn round_to_business_day<F>(date: Date, is_business_day: F, step: i32) -> Option<Date>
+where
+    F: Fn(Date) -> bool,
+{
+    let mut current = date;
+    while !is_business_day(current) {
+        current = current.checked_add(step)?
+    }
+    Some(current)
+}
+
+impl BusinessDayConvention {
+    /// Adjusts `date` to a business day according to this convention, `date` is
+    /// returned as is when it already satisfies `is_business_day`. Returns `None`
+    /// if the adjusted date would be out of `[Date::MIN, Date::MAX]`.
+    pub fn adjust<F>(self, date: Date, is_business_day: F) -> Option<Date>
+    where
+        F: Fn(Date) -> bool,
+    {
+        let same_month = |d: Date| d.month() == date.month() && d.year() == date.year();
+        match self {
+            Self::Unadjusted => Some(date),
+            Self::Following => round_to_business_day(date, is_business_day, 1),
+            Self::Preceding => round_to_business_day(date, is_business_day, -1),
+            Self::ModifiedFollowing => match round_to_business_day(date, &is_business_day, 1) {
+                Some(following) if same_month(following) => Some(following),
+                _ => round_to_business_day(date, &is_business_day, -1),
+            },
+            Self::ModifiedPreceding => match round_to_business_day(date, &is_business_day, -1) {
+                Some(preceding) if same_month(preceding) => Some(preceding),
+                _ => round_to_business_day(date, &is_business_day, 1),
+            },
+        }
+    }
+}
+
+/// How the day of the month is chosen for the dates of a schedule.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RollRule {
+    /// Use the day of the month of the start date, or the last day of the month
+    /// if the month is too short.
+    StartDay,
+    /// Use the last day of the month.
+    EndOfMonth,
+    /// Use the third Wednesday of the month.
+    Imm,
+    /// Use the given day of the month, or the last day of the month if the month
+    /// is too short.
+    DayOfMonth(u8),
+}
+
+impl RollRule {
+    /// Applies this rule to the month of `date`, returns `None` if the rolled
+    /// date cannot be represented.
+    pub fn roll(self, date: Date) -> Option<Date> {
+        match self {
+            Self::StartDay => Some(date),
+            Self::EndOfMonth => Some(date.last_day_of_month()),
+            Self::Imm => date.nth_weekday_of_month(3, DayOfWeek::Wed),
+            Self::DayOfMonth(day) => {
+                let last_day = date.last_day_of_month();
+                let day = day.clamp(1, last_day.day());
+                Date::create(date.year(), date.month(), day).ok()
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ScheduleError {
+    NonPositivePeriod(i32),
+    StartAfterEnd(Date, Date),
+    // The date could not be rolled or adjusted within `[Date::MIN, Date::MAX]`.
+    DateOutOfRange(Date),
+}
+
+impl std::fmt::Display for ScheduleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl std::error::Error for ScheduleError {}
+
+/// A list of dates, e.g. the payment dates of a bond, together with the same
+/// dates adjusted to business days.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Schedule {
+    unadjusted: Vec<Date>,
+    adjusted: Vec<Date>,
+}
+
+impl Schedule {
+    /// Generates a schedule going forward from `start` by steps of `period_months`
+    /// months, both `start` and `end` are included. The intermediate dates are
+    /// obtained by applying the roll rule to `start` plus some number of periods,
+    /// if `end` does not fall on such a date, the last period is a short stub.
+    /// The adjusted dates are obtained by applying the business day convention
+    /// to each of the unadjusted dates.
+    pub fn generate<F>(
+        start: Date,
+        end: Date,
+        period_months: i32,
+        roll_rule: RollRule,
+        convention: BusinessDayConvention,
+        is_business_day: F,
+    ) -> Result<Self, ScheduleError>
+    where
+        F: Fn(Date) -> bool,
+    {
+        if period_months <= 0 {
+            return Err(ScheduleError::NonPositivePeriod(period_months));
+        }
+        if start > end {
+            return Err(ScheduleError::StartAfterEnd(start, end));
+        }
+        let months_to_end = (end.year() as i32 - start.year() as i32) * 12 + end.month_int() as i32
+            - start.month_int() as i32;
+        let mut unadjusted = vec![start];
+        for index in 1..=months_to_end / period_months {
+            // Always start from the start date to avoid drifting because of short months.
+            let date = start.add_months(index * period_months);
+            let date = roll_rule.roll(date).ok_or(ScheduleError::DateOutOfRange(date))?;
+            if date >= end {
+                break;
+            }
+            if date > *unadjusted.last().unwrap() {
+                unadjusted.push(date)
+            }
+        }
+        if end > start {
+            unadjusted.push(end)
+        }
+        let adjusted = unadjusted
+            .iter()
+            .map(|&d| {
+                convention.adjust(d, &is_business_day).ok_or(ScheduleError::DateOutOfRange(d))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { unadjusted, adjusted })
+    }
+
+    /// The dates of this schedule before business day adjustment.
+    pub fn unadjusted(&self) -> &[Date] {
+        &self.unadjusted
+    }
+
+    /// The dates of this schedule after business day adjustment.
+    pub fn adjusted(&self) -> &[Date] {
+        &self.adjusted
+    }
+}
@@ -67,6 +67,246 @@ impl Span {
         let s_minus = self.0 - 1;
         Self(s_minus - s_minus.rem_euclid(rhs.0) + rhs.0)
     }
+
+    /// The smallest multiple of `rhs` that is not smaller than this span, returns
+    /// `None` if it is out of `[MIN_VALUE, MAX_VALUE]`.
+    pub const fn checked_next_multiple(self, rhs: Self) -> Option<Self> {
+        let s_minus = self.0 as i128 - 1;
+        let rhs = rhs.0.unsigned_abs() as i128;
+        let ns = s_minus - s_minus.rem_euclid(rhs) + rhs;
+        if ns > i64::MAX as i128 {
+            None
+        } else {
+            Some(Self(ns as i64))
+        }
+    }
+}
+
+impl Span {
+    /// The largest representable span, roughly 292 years.
+    pub const MAX_VALUE: Self = Self(i64::MAX);
+    /// The smallest representable span, this is the opposite of `MAX_VALUE` so that
+    /// negating a span never overflows. `Span::MIN` is one minute.
+    pub const MIN_VALUE: Self = Self(-i64::MAX);
+
+    const fn of_checked(ns: Option<i64>) -> Option<Self> {
+        match ns {
+            Some(ns) if ns != i64::MIN => Some(Self(ns)),
+            _ => None,
+        }
+    }
+
+    const fn of_saturating(ns: i64) -> Self {
+        if ns == i64::MIN {
+            Self::MIN_VALUE
+        } else {
+            Self(ns)
+        }
+    }
+
+    /// Returns `None` if the result is out of `[MIN_VALUE, MAX_VALUE]`.
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        Self::of_checked(self.0.checked_add(rhs.0))
+    }
+
+    /// Returns `None` if the result is out of `[MIN_VALUE, MAX_VALUE]`.
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Self::of_checked(self.0.checked_sub(rhs.0))
+    }
+
+    /// Returns `None` if the result is out of `[MIN_VALUE, MAX_VALUE]`, e.g.
+    /// `Span::DAY.checked_mul(n)` is the checked version of `Span::of_int_day(n)`.
+    pub const fn checked_mul(self, rhs: i64) -> Option<Self> {
+        Self::of_checked(self.0.checked_mul(rhs))
+    }
+
+    /// Returns `None` if `rhs` is zero.
+    pub const fn checked_div(self, rhs: i64) -> Option<Self> {
+        Self::of_checked(self.0.checked_div(rhs))
+    }
+
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        Self::of_saturating(self.0.saturating_add(rhs.0))
+    }
+
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        Self::of_saturating(self.0.saturating_sub(rhs.0))
+    }
+
+    pub const fn saturating_mul(self, rhs: i64) -> Self {
+        Self::of_saturating(self.0.saturating_mul(rhs))
+    }
+}
+
+/// The units used when formatting a span in a single unit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SpanUnit {
+    Ns,
+    Us,
+    Ms,
+    Sec,
+    Min,
+    Hr,
+    Day,
+}
+
+impl SpanUnit {
+    pub const fn span(self) -> Span {
+        match self {
+            Self::Ns => Span::NS,
+            Self::Us => Span::US,
+            Self::Ms => Span::MS,
+            Self::Sec => Span::SEC,
+            Self::Min => Span::MIN,
+            Self::Hr => Span::HR,
+            Self::Day => Span::DAY,
+        }
+    }
+
+    /// The suffix used for this unit, this is the same as in the `Span` string
+    /// representation, e.g. "ms" or "d".
+    pub const fn suffix(self) -> &'static str {
+        match self {
+            Self::Ns => "ns",
+            Self::Us => "us",
+            Self::Ms => "ms",
+            Self::Sec => "s",
+            Self::Min => "m",
+            Self::Hr => "h",
+            Self::Day => "d",
+        }
+    }
+
+    /// The largest unit that is not larger than the absolute value of `span`, or
+    /// `Ns` for spans shorter than a nanosecond.
+    pub fn of_span(span: Span) -> Self {
+        let ns = span.0.unsigned_abs();
+        [Self::Day, Self::Hr, Self::Min, Self::Sec, Self::Ms, Self::Us]
+            .into_iter()
+            .find(|unit| ns >= unit.span().0 as u64)
+            .unwrap_or(Self::Ns)
+    }
+}
+
+/// How to round a span to a multiple of some unit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round to the closest multiple, ties are rounded up.
+    Nearest,
+    /// Round towards negative infinity.
+    Down,
+    /// Round towards positive infinity.
+    Up,
+}
+
+// The absolute value of `ns` expressed in `unit_ns` and scaled by 10^decimals,
+// rounded to the nearest integer with ties away from zero.
+fn scaled_abs(ns: i64, unit_ns: i64, decimals: u32) -> i128 {
+    let num = ns.unsigned_abs() as i128 * 10i128.pow(decimals);
+    let unit_ns = unit_ns as i128;
+    (2 * num + unit_ns) / (2 * unit_ns)
+}
+
+// Spans are at most 9.3e18ns so more decimals than this are never useful.
+const MAX_DECIMALS: u32 = 18;
+
+impl Span {
+    /// String representation using a single unit with a fixed number of decimals,
+    /// e.g. "1.52h" or "912.3ms". The value is rounded to the nearest.
+    pub fn to_string_in_unit(self, unit: SpanUnit, decimals: u32) -> String {
+        let decimals = decimals.min(MAX_DECIMALS);
+        let scaled = scaled_abs(self.0, unit.span().0, decimals);
+        let sign = if self.0 < 0 && scaled != 0 { "-" } else { "" };
+        let pow = 10i128.pow(decimals);
+        let (int, frac) = (scaled / pow, scaled % pow);
+        let suffix = unit.suffix();
+        if decimals == 0 {
+            format!("{sign}{int}{suffix}")
+        } else {
+            let width = decimals as usize;
+            format!("{sign}{int}.{frac:0width$}{suffix}")
+        }
+    }
+
+    /// String representation using a single unit with the given number of
+    /// significant digits, e.g. "1.5h" or "0.0012s" with two significant digits.
+    /// Integer digits are never dropped, e.g. "912ms" with one significant digit.
+    pub fn to_string_in_unit_significant(self, unit: SpanUnit, digits: u32) -> String {
+        // Spans have at most 19 integer digits in any unit.
+        let digits = digits.clamp(1, MAX_DECIMALS + 19);
+        let unit_ns = unit.span().0 as u128;
+        let ns = self.0.unsigned_abs() as u128;
+        let decimals = if ns == 0 {
+            digits - 1
+        } else if ns >= unit_ns {
+            let int_digits = (ns / unit_ns).ilog10() + 1;
+            digits.saturating_sub(int_digits)
+        } else {
+            // The number of zeros between the decimal point and the first digit.
+            let mut leading_zeros = 0;
+            while ns * 10u128.pow(leading_zeros + 1) < unit_ns {
+                leading_zeros += 1
+            }
+            leading_zeros + digits
+        };
+        let mut decimals = decimals.min(MAX_DECIMALS);
+        // Rounding may add a digit, e.g. 9.96 with two significant digits is 10.
+        if decimals > 0 && scaled_abs(self.0, unit_ns as i64, decimals) >= 10i128.pow(digits) {
+            decimals -= 1
+        }
+        self.to_string_in_unit(unit, decimals)
+    }
+
+    /// A human readable representation using the largest unit that is not larger
+    /// than this span and up to three decimals, e.g. "1.52h", "912.3ms", or "3d".
+    /// This is similar to `to_string_hum` in the OCaml implementation.
+    pub fn to_string_hum(self) -> String {
+        if self.0 == 0 {
+            return "0s".to_string();
+        }
+        let unit_ns = SpanUnit::of_span(self).span().0 as i128;
+        // Rounding to three decimals can reach the next unit, e.g. 59.9999s is 1m.
+        let rounded = scaled_abs(self.0, unit_ns as i64, 3) * unit_ns / 1000;
+        let unit = SpanUnit::of_span(Self(rounded.min(i64::MAX as i128) as i64));
+        let s = self.to_string_in_unit(unit, 3);
+        let (value, suffix) = s.split_at(s.len() - unit.suffix().len());
+        let value = value.trim_end_matches('0').trim_end_matches('.');
+        format!("{value}{suffix}")
+    }
+
+    /// Rounds this span to a multiple of `unit` using the given rounding mode.
+    /// This panics if `unit` is zero.
+    pub fn round(self, unit: Self, mode: RoundingMode) -> Self {
+        match mode {
+            RoundingMode::Nearest => self.round_nearest(unit),
+            RoundingMode::Down => self.round_down(unit),
+            RoundingMode::Up => self.round_up(unit),
+        }
+    }
+
+    /// Rounds to the closest multiple of `unit`, ties are rounded up. This
+    /// saturates at `MIN_VALUE` or `MAX_VALUE` if the multiple is out of range.
+    pub fn round_nearest(self, unit: Self) -> Self {
+        let rem = self.0.rem_euclid(unit.0) as i128;
+        if 2 * rem >= unit.0.abs() as i128 {
+            self.round_up(unit)
+        } else {
+            self.round_down(unit)
+        }
+    }
+
+    /// Rounds to the largest multiple of `unit` that is not larger than this span,
+    /// this saturates at `MIN_VALUE` if the multiple is out of range.
+    pub fn round_down(self, unit: Self) -> Self {
+        let ns = self.0.checked_sub(self.0.rem_euclid(unit.0));
+        Self::of_checked(ns).unwrap_or(Self::MIN_VALUE)
+    }
+
+    /// Rounds to the smallest multiple of `unit` that is not smaller than this span,
+    /// this saturates at `MAX_VALUE` if the multiple is out of range.
+    pub fn round_up(self, unit: Self) -> Self {
+        self.checked_next_multiple(unit).unwrap_or(Self::MAX_VALUE)
+    }
 }
 
 fn remove_trailing_zeros(value: i64, max_digits: usize) -> (i64, usize) {
@@ -143,12 +383,22 @@ impl std::fmt::Debug for Span {
     }
 }
 
+/// Errors returned when parsing a span, positions are byte offsets in the input.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ParseSpanError {
     EmptyString,
     UnexpectedChar(String, char),
     UnexpectedCharAfterU(String),
     UnexpectedCharAfterN(String),
+    Overflow(String),
+    // The following errors are only returned by `Span::of_string_strict`.
+    InvalidChar(String, usize, char),
+    MissingNumber(String, usize),
+    MissingUnit(String, usize),
+    MultipleDots(String, usize),
+    DuplicateUnit(String, usize),
+    UnitOutOfOrder(String, usize),
+    SubNanosecond(String, usize),
 }
 
 impl std::fmt::Display for ParseSpanError {
@@ -159,11 +409,24 @@ impl std::fmt::Display for ParseSpanError {
 
 impl std::error::Error for ParseSpanError {}
 
+// The span for `value.frac_value` units, where `frac_value` has been scaled
+// by `frac_digits`. The fractional part is truncated to the nanosecond.
+fn scaled_unit(unit: Span, value: i64, frac_value: i64, frac_digits: i64) -> Option<Span> {
+    let frac = unit.0 as i128 * frac_value as i128 / frac_digits as i128;
+    unit.checked_mul(value)?.checked_add(Span(frac as i64))
+}
+
+// Fractional digits after the 18th one are ignored, they are well below the
+// nanosecond for all the supported units.
+const MAX_FRAC_DIGITS: i64 = 1_000_000_000_000_000_000;
+
 impl std::str::FromStr for Span {
     type Err = ParseSpanError;
 
     // Note that "1d1d" is allowed, as it is in the OCaml implementation.
+    // See `Span::of_string_strict` for a stricter version.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let overflow = || ParseSpanError::Overflow(s.to_string());
         let mut chars = s.chars().peekable();
         let negative = match chars.peek() {
             None => return Err(ParseSpanError::EmptyString),
@@ -178,89 +441,58 @@ impl std::str::FromStr for Span {
             Some(_) => false,
         };
         let mut res = Span::ZERO;
-        let mut value = 0;
+        let mut value: i64 = 0;
         let mut frac_value = 0;
         let mut frac_digits = 1;
         let mut after_point = false;
         while let Some(c) = chars.next() {
             // d, h, m, s, ms, us, ns
-            let should_init = match c {
+            let unit = match c {
                 '.' => {
                     after_point = true;
-                    false
-                }
-                'd' => {
-                    res += Span::DAY * value;
-                    if frac_value != 0 {
-                        res += (Span::DAY * frac_value) / frac_digits
-                    }
-                    true
-                }
-                'h' => {
-                    res += Span::HR * value;
-                    if frac_value != 0 {
-                        res += (Span::HR * frac_value) / frac_digits
-                    }
-                    true
-                }
-                'm' => {
-                    match chars.next_if_eq(&'s') {
-                        Some(_) => {
-                            res += Span::MS * value;
-                            if frac_value != 0 {
-                                res += (Span::MS * frac_value) / frac_digits
-                            }
-                        }
-                        None => {
-                            res += Span::MIN * value;
-                            if frac_value != 0 {
-                                res += (Span::MIN * frac_value) / frac_digits
-                            }
-                        }
-                    }
-                    true
-                }
-                's' => {
-                    res += Span::SEC * value;
-                    if frac_value != 0 {
-                        res += (Span::SEC * frac_value) / frac_digits
-                    }
-                    true
+                    None
                 }
+                'd' => Some(Span::DAY),
+                'h' => Some(Span::HR),
+                'm' => match chars.next_if_eq(&'s') {
+                    Some(_) => Some(Span::MS),
+                    None => Some(Span::MIN),
+                },
+                's' => Some(Span::SEC),
                 'u' => match chars.next_if_eq(&'s') {
-                    Some(_) => {
-                        res += Span::US * value;
-                        if frac_value != 0 {
-                            res += (Span::US * frac_value) / frac_digits
-                        }
-                        true
-                    }
+                    Some(_) => Some(Span::US),
                     None => return Err(ParseSpanError::UnexpectedCharAfterU(s.to_string())),
                 },
                 'n' => match chars.next_if_eq(&'s') {
                     Some(_) => {
-                        res += Span::NS * value;
-                        if frac_value * 2 > frac_digits {
-                            res += Span::NS;
-                        }
-                        true
+                        // Nanoseconds are rounded rather than truncated.
+                        let round = if frac_value * 2 > frac_digits { 1 } else { 0 };
+                        value = value.checked_add(round).ok_or_else(overflow)?;
+                        frac_value = 0;
+                        Some(Span::NS)
                     }
                     None => return Err(ParseSpanError::UnexpectedCharAfterN(s.to_string())),
                 },
                 other => match other.to_digit(10) {
                     Some(digit) => {
-                        if after_point {
+                        if !after_point {
+                            value = value
+                                .checked_mul(10)
+                                .and_then(|v| v.checked_add(digit as i64))
+                                .ok_or_else(overflow)?;
+                        } else if frac_digits < MAX_FRAC_DIGITS {
                             frac_value = 10 * frac_value + digit as i64;
                             frac_digits *= 10;
-                        } else {
-                            value = 10 * value + digit as i64;
                         }
-                        false
+                        None
                     }
                     None => return Err(ParseSpanError::UnexpectedChar(s.to_string(), other)),
                 },
             };
-            if should_init {
+            if let Some(unit) = unit {
+                let span =
+                    scaled_unit(unit, value, frac_value, frac_digits).ok_or_else(overflow)?;
+                res = res.checked_add(span).ok_or_else(overflow)?;
                 value = 0;
                 frac_value = 0;
                 frac_digits = 1;
@@ -274,6 +506,272 @@ impl std::str::FromStr for Span {
     }
 }
 
+impl Span {
+    /// Parses a span such as "1d2h3.5s" rejecting inputs accepted by the lenient
+    /// `from_str`: each component must have a number and a unit, units must be in
+    /// decreasing order without duplicates, and fractions must not be below the
+    /// nanosecond.
+    pub fn of_string_strict(s: &str) -> Result<Self, ParseSpanError> {
+        let err_at = |f: fn(String, usize) -> ParseSpanError, pos: usize| f(s.to_string(), pos);
+        let overflow = || ParseSpanError::Overflow(s.to_string());
+        let (negative, start) = match s.as_bytes().first() {
+            None => return Err(ParseSpanError::EmptyString),
+            Some(b'+') => (false, 1),
+            Some(b'-') => (true, 1),
+            Some(_) => (false, 0),
+        };
+        if start == s.len() {
+            return Err(err_at(ParseSpanError::MissingNumber, start));
+        }
+        let mut chars = s.char_indices().skip(start).peekable();
+        let mut res = Span::ZERO;
+        // The index of the last unit in "d", "h", "m", "s", "ms", "us", "ns".
+        let mut last_unit = None;
+        while chars.peek().is_some() {
+            let number_start = chars.peek().map_or(s.len(), |(pos, _)| *pos);
+            let mut value: i64 = 0;
+            let mut frac_value = 0;
+            let mut frac_digits = 1;
+            let mut digits = 0;
+            let mut dot = false;
+            let mut sub_ns = false;
+            let (unit_pos, c) = loop {
+                let (pos, c) = match chars.next() {
+                    None => return Err(err_at(ParseSpanError::MissingUnit, s.len())),
+                    Some(pc) => pc,
+                };
+                if c == '.' {
+                    if dot {
+                        return Err(err_at(ParseSpanError::MultipleDots, pos));
+                    }
+                    dot = true;
+                } else if let Some(digit) = c.to_digit(10) {
+                    digits += 1;
+                    if !dot {
+                        value = value
+                            .checked_mul(10)
+                            .and_then(|v| v.checked_add(digit as i64))
+                            .ok_or_else(overflow)?;
+                    } else if frac_digits < MAX_FRAC_DIGITS {
+                        frac_value = 10 * frac_value + digit as i64;
+                        frac_digits *= 10;
+                    } else if digit != 0 {
+                        sub_ns = true;
+                    }
+                } else {
+                    break (pos, c);
+                }
+            };
+            if digits == 0 {
+                return Err(err_at(ParseSpanError::MissingNumber, number_start));
+            }
+            let (index, unit) = match c {
+                'd' => (0, Span::DAY),
+                'h' => (1, Span::HR),
+                'm' => match chars.next_if(|(_, c)| *c == 's') {
+                    Some(_) => (4, Span::MS),
+                    None => (2, Span::MIN),
+                },
+                's' => (3, Span::SEC),
+                'u' | 'n' => match chars.next() {
+                    Some((_, 's')) if c == 'u' => (5, Span::US),
+                    Some((_, 's')) => (6, Span::NS),
+                    Some((pos, c)) => {
+                        return Err(ParseSpanError::InvalidChar(s.to_string(), pos, c))
+                    }
+                    None => return Err(ParseSpanError::InvalidChar(s.to_string(), unit_pos, c)),
+                },
+                _ => return Err(ParseSpanError::InvalidChar(s.to_string(), unit_pos, c)),
+            };
+            match last_unit {
+                Some(last) if last == index => {
+                    return Err(err_at(ParseSpanError::DuplicateUnit, unit_pos))
+                }
+                Some(last) if last > index => {
+                    return Err(err_at(ParseSpanError::UnitOutOfOrder, unit_pos))
+                }
+                _ => {}
+            }
+            last_unit = Some(index);
+            let frac = unit.0 as i128 * frac_value as i128;
+            if sub_ns || frac % frac_digits as i128 != 0 {
+                return Err(err_at(ParseSpanError::SubNanosecond, number_start));
+            }
+            let span = scaled_unit(unit, value, frac_value, frac_digits).ok_or_else(overflow)?;
+            res = res.checked_add(span).ok_or_else(overflow)?;
+        }
+        Ok(if negative { -res } else { res })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseIso8601SpanError {
+    EmptyString,
+    MissingP(String),
+    // Years and months do not have a fixed length, `Period` should be used instead.
+    CalendarUnit(String, char),
+    UnexpectedChar(String, char),
+    MissingNumber(String, char),
+    MissingUnit(String),
+    FractionNotLast(String),
+    // Designators must appear at most once and in the order W, D, H, M, S.
+    UnitOutOfOrder(String, char),
+    RepeatedT(String),
+    // A 'T' must be followed by at least one hour, minute, or second component.
+    DanglingT(String),
+    Overflow(String),
+}
+
+impl std::fmt::Display for ParseIso8601SpanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl std::error::Error for ParseIso8601SpanError {}
+
+impl Span {
+    /// String representation using an ISO 8601 duration, e.g. "PT1H30M" or "P3DT4H".
+    /// Days are always 24 hours long and years and months are never used.
+    pub fn to_string_iso8601(self) -> String {
+        if self.0 == 0 {
+            return "PT0S".to_string();
+        }
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let ns = self.0.unsigned_abs();
+        let day_ns = Self::DAY.0 as u64;
+        let (days, ns) = (ns / day_ns, ns % day_ns);
+        let mut s = format!("{sign}P");
+        if days != 0 {
+            s.push_str(&format!("{days}D"))
+        }
+        if ns != 0 {
+            s.push('T');
+            let hr_ns = Self::HR.0 as u64;
+            let min_ns = Self::MIN.0 as u64;
+            let sec_ns = Self::SEC.0 as u64;
+            let (hr, ns) = (ns / hr_ns, ns % hr_ns);
+            let (min, ns) = (ns / min_ns, ns % min_ns);
+            let (sec, ns) = (ns / sec_ns, ns % sec_ns);
+            if hr != 0 {
+                s.push_str(&format!("{hr}H"))
+            }
+            if min != 0 {
+                s.push_str(&format!("{min}M"))
+            }
+            if ns != 0 {
+                let (ns, width) = remove_trailing_zeros(ns as i64, 9);
+                s.push_str(&format!("{sec}.{ns:0width$}S"))
+            } else if sec != 0 {
+                s.push_str(&format!("{sec}S"))
+            }
+        }
+        s
+    }
+
+    /// Parses an ISO 8601 duration, e.g. "PT1H30M", "P3DT4H", or "-PT0.5S".
+    /// Weeks and days are accepted and assumed to be 7 days and 24 hours long,
+    /// years and months are rejected as they do not have a fixed length. A
+    /// fraction, using either '.' or ',', is only allowed on the last component.
+    pub fn of_string_iso8601(s: &str) -> Result<Self, ParseIso8601SpanError> {
+        let (negative, body) = match s.strip_prefix('-') {
+            Some(body) => (true, body),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if body.is_empty() {
+            return Err(ParseIso8601SpanError::EmptyString);
+        }
+        let body = match body.strip_prefix(['P', 'p']) {
+            Some(body) => body,
+            None => return Err(ParseIso8601SpanError::MissingP(s.to_string())),
+        };
+        let overflow = || ParseIso8601SpanError::Overflow(s.to_string());
+        let mut in_time = false;
+        let mut has_component = false;
+        // The index of the last designator in W, D, H, M, S.
+        let mut last_unit: Option<usize> = None;
+        let mut after_fraction = false;
+        let mut total: i128 = 0;
+        let mut chars = body.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == 'T' || c == 't' {
+                if in_time {
+                    return Err(ParseIso8601SpanError::RepeatedT(s.to_string()));
+                }
+                in_time = true;
+                continue;
+            }
+            if after_fraction {
+                return Err(ParseIso8601SpanError::FractionNotLast(s.to_string()));
+            }
+            let mut value: i128 = 0;
+            let mut frac: i128 = 0;
+            let mut frac_digits = 0;
+            let mut digits = 0;
+            let mut c = c;
+            while let Some(digit) = c.to_digit(10) {
+                value = 10 * value + digit as i128;
+                digits += 1;
+                if value > i64::MAX as i128 {
+                    return Err(overflow());
+                }
+                c = chars
+                    .next()
+                    .ok_or_else(|| ParseIso8601SpanError::MissingUnit(s.to_string()))?;
+            }
+            if c == '.' || c == ',' {
+                after_fraction = true;
+                c = chars
+                    .next()
+                    .ok_or_else(|| ParseIso8601SpanError::MissingUnit(s.to_string()))?;
+                while let Some(digit) = c.to_digit(10) {
+                    // Digits after the ninth one are below the nanosecond for seconds.
+                    if frac_digits < 18 {
+                        frac = 10 * frac + digit as i128;
+                        frac_digits += 1;
+                    }
+                    digits += 1;
+                    c = chars
+                        .next()
+                        .ok_or_else(|| ParseIso8601SpanError::MissingUnit(s.to_string()))?;
+                }
+            }
+            let (index, unit) = match (in_time, c.to_ascii_uppercase()) {
+                (false, 'W') => (0, Self::DAY.0 * 7),
+                (false, 'D') => (1, Self::DAY.0),
+                (false, 'Y') | (false, 'M') => {
+                    return Err(ParseIso8601SpanError::CalendarUnit(s.to_string(), c))
+                }
+                (true, 'H') => (2, Self::HR.0),
+                (true, 'M') => (3, Self::MIN.0),
+                (true, 'S') => (4, Self::SEC.0),
+                _ => return Err(ParseIso8601SpanError::UnexpectedChar(s.to_string(), c)),
+            };
+            let unit = unit as i128;
+            if digits == 0 {
+                return Err(ParseIso8601SpanError::MissingNumber(s.to_string(), c));
+            }
+            if last_unit.is_some_and(|last| last >= index) {
+                return Err(ParseIso8601SpanError::UnitOutOfOrder(s.to_string(), c));
+            }
+            last_unit = Some(index);
+            has_component = true;
+            total += value * unit + frac * unit / 10i128.pow(frac_digits);
+            if total > i64::MAX as i128 {
+                return Err(overflow());
+            }
+        }
+        if !has_component {
+            return Err(ParseIso8601SpanError::MissingUnit(s.to_string()));
+        }
+        if in_time && last_unit < Some(2) {
+            return Err(ParseIso8601SpanError::DanglingT(s.to_string()));
+        }
+        let total = if negative { -total } else { total };
+        Ok(Self(total as i64))
+    }
+}
+
 impl Add for Span {
     type Output = Self;
 
@@ -0,0 +1,83 @@
+use crate::{Span, Time};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The errors of the conversions between `Span` and `std::time::Duration`, and
+/// between `Time` and `std::time::SystemTime`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StdTimeError {
+    // `Duration` cannot represent negative spans.
+    NegativeSpan(Span),
+    // The value does not fit in the 64 bits used by `Span` and `Time`.
+    OutOfRange,
+}
+
+impl std::fmt::Display for StdTimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl std::error::Error for StdTimeError {}
+
+impl TryFrom<Span> for Duration {
+    type Error = StdTimeError;
+
+    fn try_from(span: Span) -> Result<Self, Self::Error> {
+        if span.is_negative() {
+            return Err(StdTimeError::NegativeSpan(span));
+        }
+        Ok(Duration::from_nanos(span.to_int_ns() as u64))
+    }
+}
+
+impl TryFrom<Duration> for Span {
+    type Error = StdTimeError;
+
+    fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+        let ns = i64::try_from(duration.as_nanos()).map_err(|_| StdTimeError::OutOfRange)?;
+        Ok(Span::of_int_ns(ns))
+    }
+}
+
+impl Span {
+    /// The absolute value of this span as a `Duration`, this never fails.
+    pub fn unsigned_abs_duration(self) -> Duration {
+        Duration::from_nanos(self.to_int_ns().unsigned_abs())
+    }
+
+    /// Converts to a `Duration`, negative spans result in a zero duration, e.g.
+    /// to pass a deadline that may have passed to `std::thread::sleep`.
+    pub fn to_duration_saturating(self) -> Duration {
+        if self.is_negative() {
+            Duration::ZERO
+        } else {
+            self.unsigned_abs_duration()
+        }
+    }
+}
+
+impl From<Time> for SystemTime {
+    fn from(time: Time) -> Self {
+        let ns = time.to_int_ns_since_epoch();
+        let duration = Duration::from_nanos(ns.unsigned_abs());
+        if ns < 0 {
+            UNIX_EPOCH - duration
+        } else {
+            UNIX_EPOCH + duration
+        }
+    }
+}
+
+// Times are converted through spans, so exactly 2^63 nanoseconds before the
+// epoch is out of range even though it is `Time::MIN`.
+impl TryFrom<SystemTime> for Time {
+    type Error = StdTimeError;
+
+    fn try_from(system_time: SystemTime) -> Result<Self, Self::Error> {
+        let span = match system_time.duration_since(UNIX_EPOCH) {
+            Ok(duration) => Span::try_from(duration)?,
+            Err(err) => -Span::try_from(err.duration())?,
+        };
+        Ok(Time::of_span_since_epoch(span))
+    }
+}
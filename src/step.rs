@@ -0,0 +1,155 @@
+use crate::{OfDay, Span, Time};
+
+// The values lo + i * step for i in [front, back). The indexes are u128 as
+// there can be 2^64 values, e.g. all the nanoseconds between `Time::MIN` and
+// `Time::MAX`.
+#[derive(Clone, Debug)]
+struct Steps {
+    lo: i64,
+    step: i64,
+    front: u128,
+    back: u128,
+}
+
+impl Steps {
+    // The steps in the inclusive range [lo, up], this is empty when
+    // `lo` is after `up`.
+    fn new(lo: i64, up: i64, step: Span) -> Self {
+        assert!(step > Span::ZERO, "step must be positive, got {step:?}");
+        let step = step.to_int_ns();
+        let back = if lo <= up { (up as i128 - lo as i128) as u128 / step as u128 + 1 } else { 0 };
+        Self { lo, step, front: 0, back }
+    }
+
+    // The multiples of `step` in [lo, up], this is empty when the first multiple
+    // after `lo` does not fit in an i64.
+    fn aligned(lo: i64, up: i64, step: Span) -> Self {
+        assert!(step > Span::ZERO, "step must be positive, got {step:?}");
+        let step_ns = step.to_int_ns() as i128;
+        let lo_minus = lo as i128 - 1;
+        match i64::try_from(lo_minus - lo_minus.rem_euclid(step_ns) + step_ns) {
+            Ok(lo) => Self::new(lo, up, step),
+            Err(_) => Self { lo, step: step.to_int_ns(), front: 0, back: 0 },
+        }
+    }
+
+    fn value(&self, index: u128) -> i64 {
+        (self.lo as i128 + index as i128 * self.step as i128) as i64
+    }
+
+    fn next(&mut self) -> Option<i64> {
+        if self.front < self.back {
+            let res = self.value(self.front);
+            self.front += 1;
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    fn next_back(&mut self) -> Option<i64> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.value(self.back))
+        } else {
+            None
+        }
+    }
+
+    // The number of remaining values, saturated at `usize::MAX`, and the exact
+    // number when it fits in a `usize`.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.back - self.front) {
+            Ok(len) => (len, Some(len)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+/// The times between two bounds (inclusive) separated by a fixed step. This
+/// does not implement `ExactSizeIterator` as there can be more than
+/// `usize::MAX` times.
+#[derive(Clone, Debug)]
+pub struct TimesBetween(Steps);
+
+impl Iterator for TimesBetween {
+    type Item = Time;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(Time::of_int_ns_since_epoch)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for TimesBetween {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(Time::of_int_ns_since_epoch)
+    }
+}
+
+impl std::iter::FusedIterator for TimesBetween {}
+
+impl Time {
+    /// Lists the times lo, lo + step, lo + 2 * step, ... up to `up` (inclusive).
+    /// This panics if `step` is not positive.
+    pub fn times_between(lo: Self, up: Self, step: Span) -> TimesBetween {
+        TimesBetween(Steps::new(lo.to_int_ns_since_epoch(), up.to_int_ns_since_epoch(), step))
+    }
+
+    /// Lists the times from `self` until `up` (inclusive) separated by `step`.
+    pub fn times_until(self, up: Self, step: Span) -> TimesBetween {
+        Self::times_between(self, up, step)
+    }
+
+    /// Lists the multiples of `step` since the epoch between `lo` and `up`
+    /// (inclusive), e.g. every full five minutes.
+    pub fn aligned_times_between(lo: Self, up: Self, step: Span) -> TimesBetween {
+        TimesBetween(Steps::aligned(lo.to_int_ns_since_epoch(), up.to_int_ns_since_epoch(), step))
+    }
+}
+
+/// The times of day between two bounds (inclusive) separated by a fixed step. This
+/// does not implement `ExactSizeIterator` as the number of nanoseconds in a day
+/// does not fit in a 32-bit `usize`.
+#[derive(Clone, Debug)]
+pub struct OfDaysBetween(Steps);
+
+impl Iterator for OfDaysBetween {
+    type Item = OfDay;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(OfDay::of_ns_since_midnight)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for OfDaysBetween {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(OfDay::of_ns_since_midnight)
+    }
+}
+
+impl std::iter::FusedIterator for OfDaysBetween {}
+
+impl OfDay {
+    /// Lists the times of day lo, lo + step, lo + 2 * step, ... up to `up`
+    /// (inclusive). This panics if `step` is not positive.
+    pub fn ofdays_between(lo: Self, up: Self, step: Span) -> OfDaysBetween {
+        OfDaysBetween(Steps::new(lo.to_ns_since_midnight(), up.to_ns_since_midnight(), step))
+    }
+
+    /// Lists the times of day from `self` until `up` (inclusive) separated by `step`.
+    pub fn ofdays_until(self, up: Self, step: Span) -> OfDaysBetween {
+        Self::ofdays_between(self, up, step)
+    }
+
+    /// Lists the multiples of `step` since midnight between `lo` and `up`
+    /// (inclusive), e.g. every quarter hour.
+    pub fn aligned_ofdays_between(lo: Self, up: Self, step: Span) -> OfDaysBetween {
+        OfDaysBetween(Steps::aligned(lo.to_ns_since_midnight(), up.to_ns_since_midnight(), step))
+    }
+}
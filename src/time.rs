@@ -35,6 +35,8 @@ pub enum TimeParseError {
     ExpectedIntInZone(std::num::ParseIntError),
     TzError(TzError),
     TzParseError(TzParseError),
+    ZoneOffsetMismatch(Span),
+    InvalidZoneOffset(Span),
 }
 
 impl std::fmt::Display for TimeParseError {
@@ -75,7 +77,7 @@ impl std::convert::From<ofday::ParseOfDayError> for TimeParseError {
     }
 }
 
-fn parse_zone_offset(s: &str) -> Result<Span, TimeParseError> {
+pub(crate) fn parse_zone_offset(s: &str) -> Result<Span, TimeParseError> {
     match s.split(':').collect::<Vec<_>>()[..] {
         [] => Err(TimeParseError::NoZone),
         [hour] => {
@@ -97,6 +99,24 @@ fn parse_zone_offset(s: &str) -> Result<Span, TimeParseError> {
     }
 }
 
+// Writes an offset such as "+05:30" or "-04:00", seconds are only included when
+// not zero.
+pub(crate) fn write_zone_offset<W: std::fmt::Write>(
+    w: &mut W,
+    offset_sec: i32,
+) -> Result<(), std::fmt::Error> {
+    let (abs_offset, sign) = if offset_sec < 0 { (-offset_sec, '-') } else { (offset_sec, '+') };
+    let offset_sec = abs_offset % 60;
+    let abs_offset = abs_offset / 60;
+    let offset_min = abs_offset % 60;
+    let offset_hr = abs_offset / 60;
+    write!(w, "{sign}{offset_hr:02}:{offset_min:02}")?;
+    if offset_sec != 0 {
+        write!(w, ":{offset_sec:02}")?;
+    }
+    Ok(())
+}
+
 impl Time {
     fn parse_ofday_with_zone(ofday_with_zone: &str, date: Date) -> Result<Self, TimeParseError> {
         if let Some((ofday, "")) = ofday_with_zone.split_once('Z') {
@@ -181,12 +201,47 @@ impl Rem<Span> for Time {
 
 impl Time {
     pub const EPOCH: Self = Self(0);
+    /// The earliest representable time, around 1677-09-21.
+    pub const MIN: Self = Self(i64::MIN);
+    /// The latest representable time, around 2262-04-11.
+    pub const MAX: Self = Self(i64::MAX);
+
+    /// Returns `None` if the result is out of `[MIN, MAX]`.
+    pub const fn checked_add(self, span: Span) -> Option<Self> {
+        match self.0.checked_add(span.to_int_ns()) {
+            Some(ns) => Some(Self(ns)),
+            None => None,
+        }
+    }
 
+    /// Returns `None` if the result is out of `[MIN, MAX]`.
+    pub const fn checked_sub(self, span: Span) -> Option<Self> {
+        match self.0.checked_sub(span.to_int_ns()) {
+            Some(ns) => Some(Self(ns)),
+            None => None,
+        }
+    }
+
+    /// The span between `other` and `self`, returns `None` if it does not fit in a `Span`.
+    pub const fn checked_diff(self, other: Self) -> Option<Span> {
+        match self.0.checked_sub(other.0) {
+            Some(ns) if ns != i64::MIN => Some(Span::of_int_ns(ns)),
+            _ => None,
+        }
+    }
+
+    pub const fn saturating_add(self, span: Span) -> Self {
+        Self(self.0.saturating_add(span.to_int_ns()))
+    }
+
+    pub const fn saturating_sub(self, span: Span) -> Self {
+        Self(self.0.saturating_sub(span.to_int_ns()))
+    }
+
+    /// The current time, this uses the system clock unless another clock has been
+    /// installed on the current thread with `with_clock`.
     pub fn now() -> Self {
-        let now = std::time::SystemTime::now();
-        let dt = now.duration_since(std::time::UNIX_EPOCH).expect("system time before Unix epoch");
-        let dt = Span::of_int_sec(dt.as_secs() as i64) + Span::of_int_ns(dt.subsec_nanos() as i64);
-        Self::of_span_since_epoch(dt)
+        crate::clock::now()
     }
 
     pub const fn to_span_since_epoch(self) -> Span {
@@ -237,10 +292,36 @@ impl Time {
         OfDay::of_ns_since_midnight(ofday)
     }
 
+    // Whether local times on `date` can be converted to times without overflowing.
+    // Dates next to the bounds are excluded as the timezone offset could move them
+    // out of range.
+    pub(crate) fn date_in_range(date: Date) -> bool {
+        let (min_date, _) = Self::MIN.to_date_ofday_gmt();
+        let (max_date, _) = Self::MAX.to_date_ofday_gmt();
+        min_date + 1 < date && date < max_date - 1
+    }
+
     pub fn of_date_ofday(date: Date, ofday: OfDay, tz: Tz) -> Result<Self, TzError> {
         tz.tz_info().date_ofday_to_time(date, ofday)
     }
 
+    /// Similar to `of_date_ofday` but always returns a time. When the local time
+    /// happens twice because of a daylight saving change, the earliest time is
+    /// returned. When the local time is skipped, it is shifted forward by the
+    /// length of the gap, e.g. 01:30 becomes 02:30 in London on 2021-03-28.
+    pub fn of_date_ofday_lenient(date: Date, ofday: OfDay, tz: Tz) -> Self {
+        let tz_info = tz.tz_info();
+        match tz_info.date_ofday_to_time(date, ofday) {
+            Ok(time) | Err(TzError::TwoTimesInThisTz(time, _)) => time,
+            Err(TzError::NoTimeInThisTz) => {
+                // Use the offset from before the transition, a day before the local
+                // time is always before the transition as offsets are below 24h.
+                let gmt = Self::of_date_ofday_gmt(date, ofday);
+                gmt - tz_info.offset(gmt - Span::DAY)
+            }
+        }
+    }
+
     pub fn of_date_ofday_gmt(date: Date, ofday: OfDay) -> Self {
         let gmt_ns = (date - Date::UNIX_EPOCH) as i64 * Span::DAY.to_int_ns();
         Time(gmt_ns + ofday.to_ns_since_midnight())
@@ -260,17 +341,8 @@ impl Time {
         if offset_sec == 0 {
             write!(w, "{date} {ofday}Z")
         } else {
-            let (abs_offset, sign) =
-                if offset_sec < 0 { (-offset_sec, '-') } else { (offset_sec, '+') };
-            let offset_sec = abs_offset % 60;
-            let abs_offset = abs_offset / 60;
-            let offset_min = abs_offset % 60;
-            let offset_hr = abs_offset / 60;
-            write!(w, "{date} {ofday}{sign}{offset_hr:02}:{offset_min:02}")?;
-            if offset_sec != 0 {
-                write!(w, ":{offset_sec:02}")?;
-            }
-            Ok(())
+            write!(w, "{date} {ofday}")?;
+            write_zone_offset(w, offset_sec)
         }
     }
 
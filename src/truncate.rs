@@ -0,0 +1,162 @@
+use crate::{Date, DayOfWeek, Inclusivity, OfDay, RoundingMode, Span, Time, Zone};
+
+/// The calendar units a time can be truncated or rounded to, weeks start on
+/// Monday.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CalendarUnit {
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+// The first instant of a local date, when midnight is skipped by a daylight
+// saving change this is the first time after the gap. Returns `None` for dates
+// too close to the bounds of `Time`.
+fn start_of_date(date: Date, zone: Zone) -> Option<Time> {
+    Time::date_in_range(date).then(|| zone.to_time_lenient(date, OfDay::START_OF_DAY))
+}
+
+// The local date of `time` in `zone`, this avoids overflowing near `Time::MIN`
+// and `Time::MAX` when the offset moves the local time out of range.
+fn local_date(time: Time, zone: Zone) -> Option<Date> {
+    let local = time.checked_add(zone.offset(time))?;
+    Some(local.to_date_ofday_gmt().0)
+}
+
+// The multiples of `step` in local time around `time`, for units shorter than a
+// day. Either bound is `None` when out of range.
+fn local_multiples(time: Time, step: Span, zone: Zone) -> (Option<Time>, Option<Time>) {
+    let offset = zone.offset(time).to_int_ns() as i128;
+    let local = time.to_int_ns_since_epoch() as i128 + offset;
+    let down = local - local.rem_euclid(step.to_int_ns() as i128);
+    let to_time = |ns: i128| i64::try_from(ns - offset).ok().map(Time::of_int_ns_since_epoch);
+    (to_time(down), to_time(down + step.to_int_ns() as i128))
+}
+
+impl CalendarUnit {
+    // The first date of the unit containing `date`, only for units of a day or more.
+    fn truncate_date(self, date: Date) -> Option<Date> {
+        match self {
+            Self::Minute | Self::Hour | Self::Day => Some(date),
+            Self::Week => date.prev_weekday(DayOfWeek::Mon, Inclusivity::Inclusive),
+            Self::Month => Some(date.first_day_of_month()),
+            Self::Year => Some(date.first_day_of_year()),
+        }
+    }
+
+    fn next_date(self, date: Date) -> Option<Date> {
+        match self {
+            Self::Minute | Self::Hour | Self::Day => date.checked_add(1),
+            Self::Week => date.checked_add(7),
+            Self::Month => Some(date.add_months(1)),
+            Self::Year => Some(date.add_years(1)),
+        }
+    }
+}
+
+impl Time {
+    /// Truncates this time to the start of the calendar unit containing it in the
+    /// given zone. Minutes and hours are truncated using the offset in effect at
+    /// this time, so that the two 01:30 in London on 2021-10-31 are truncated to
+    /// their respective 01:00.
+    ///
+    /// Panics if the result is out of range, see `checked_truncate`.
+    pub fn truncate(self, unit: CalendarUnit, zone: impl Into<Zone>) -> Self {
+        self.checked_truncate(unit, zone).expect("time out of range")
+    }
+
+    /// Similar to `truncate` but returns `None` rather than panicking when the
+    /// result is out of range, this also happens for units of a day or more
+    /// when the start of the unit is within a day of `Time::MIN` or `Time::MAX`.
+    pub fn checked_truncate(self, unit: CalendarUnit, zone: impl Into<Zone>) -> Option<Self> {
+        let zone = zone.into();
+        match unit {
+            CalendarUnit::Minute => local_multiples(self, Span::MIN, zone).0,
+            CalendarUnit::Hour => local_multiples(self, Span::HR, zone).0,
+            CalendarUnit::Day | CalendarUnit::Week | CalendarUnit::Month | CalendarUnit::Year => {
+                let date = unit.truncate_date(local_date(self, zone)?)?;
+                start_of_date(date, zone)
+            }
+        }
+    }
+
+    /// Rounds this time to the start of a calendar unit in the given zone, ties
+    /// are rounded up when using `RoundingMode::Nearest`.
+    ///
+    /// Panics if the result is out of range, see `checked_round_to`.
+    pub fn round_to(self, unit: CalendarUnit, mode: RoundingMode, zone: impl Into<Zone>) -> Self {
+        self.checked_round_to(unit, mode, zone).expect("time out of range")
+    }
+
+    /// Similar to `round_to` but returns `None` rather than panicking, with the
+    /// same limits as `checked_truncate`.
+    pub fn checked_round_to(
+        self,
+        unit: CalendarUnit,
+        mode: RoundingMode,
+        zone: impl Into<Zone>,
+    ) -> Option<Self> {
+        let zone = zone.into();
+        let (down, up) = match unit {
+            CalendarUnit::Minute => local_multiples(self, Span::MIN, zone),
+            CalendarUnit::Hour => local_multiples(self, Span::HR, zone),
+            CalendarUnit::Day | CalendarUnit::Week | CalendarUnit::Month | CalendarUnit::Year => {
+                let date = local_date(self, zone).and_then(|date| unit.truncate_date(date));
+                let down = date.and_then(|date| start_of_date(date, zone));
+                let up = date.and_then(|date| start_of_date(unit.next_date(date)?, zone));
+                (down, up)
+            }
+        };
+        if down == Some(self) {
+            return down;
+        }
+        match (mode, down, up) {
+            (RoundingMode::Up, _, up) => up,
+            (RoundingMode::Down, down, _) => down,
+            (RoundingMode::Nearest, Some(down), Some(up)) => {
+                if self - down < up - self {
+                    Some(down)
+                } else {
+                    Some(up)
+                }
+            }
+            (RoundingMode::Nearest, _, _) => None,
+        }
+    }
+
+    /// The first instant of the local day containing this time.
+    ///
+    /// Panics if the result is out of range, see `checked_truncate`.
+    pub fn start_of_day(self, zone: impl Into<Zone>) -> Self {
+        self.truncate(CalendarUnit::Day, zone)
+    }
+
+    /// The first instant of the local day following the one containing this time.
+    ///
+    /// Panics if the result is out of range, see `checked_start_of_next_day`.
+    pub fn start_of_next_day(self, zone: impl Into<Zone>) -> Self {
+        self.checked_start_of_next_day(zone).expect("time out of range")
+    }
+
+    /// Similar to `start_of_next_day` but returns `None` rather than panicking
+    /// when the next day starts within a day of `Time::MAX`.
+    pub fn checked_start_of_next_day(self, zone: impl Into<Zone>) -> Option<Self> {
+        let zone = zone.into();
+        start_of_date(local_date(self, zone)?.checked_add(1)?, zone)
+    }
+}
+
+impl Date {
+    /// The length of this date in the given zone, this is 23 or 25 hours on days
+    /// with a daylight saving change and 24 hours otherwise.
+    ///
+    /// Panics if the date is out of the range of `Time`.
+    pub fn day_length(self, zone: impl Into<Zone>) -> Span {
+        let zone = zone.into();
+        let start = |date| start_of_date(date, zone).expect("date out of range");
+        start(self + 1) - start(self)
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{Clock, MonoTime, Span, SystemClock, Time};
+use std::sync::atomic::{fence, AtomicI64, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// The tick counter and clocks read by `TscClock`, this can be replaced to
+/// simulate them, e.g. to test the calibration deterministically.
+pub trait TscSource {
+    fn ticks(&self) -> u64;
+
+    fn mono_now(&self) -> MonoTime;
+
+    fn system_now(&self) -> Time;
+
+    /// Waits during the initial calibration.
+    fn sleep(&self, span: Span) {
+        std::thread::sleep(span.to_duration_saturating())
+    }
+}
+
+impl<S: TscSource + ?Sized> TscSource for &S {
+    fn ticks(&self) -> u64 {
+        (**self).ticks()
+    }
+
+    fn mono_now(&self) -> MonoTime {
+        (**self).mono_now()
+    }
+
+    fn system_now(&self) -> Time {
+        (**self).system_now()
+    }
+
+    fn sleep(&self, span: Span) {
+        (**self).sleep(span)
+    }
+}
+
+impl<S: TscSource + ?Sized> TscSource for Arc<S> {
+    fn ticks(&self) -> u64 {
+        (**self).ticks()
+    }
+
+    fn mono_now(&self) -> MonoTime {
+        (**self).mono_now()
+    }
+
+    fn system_now(&self) -> Time {
+        (**self).system_now()
+    }
+
+    fn sleep(&self, span: Span) {
+        (**self).sleep(span)
+    }
+}
+
+/// The CPU timestamp counter together with the monotonic and system clocks. The
+/// counter falls back on the monotonic clock on architectures other than x86_64.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct HardwareTscSource;
+
+impl TscSource for HardwareTscSource {
+    #[cfg(target_arch = "x86_64")]
+    fn ticks(&self) -> u64 {
+        // SAFETY: rdtsc is available on all x86_64 processors.
+        unsafe { core::arch::x86_64::_rdtsc() }
+    }
+
+    #[cfg(not(target_arch = "x86_64"))]
+    fn ticks(&self) -> u64 {
+        MonoTime::now().to_int_ns() as u64
+    }
+
+    fn mono_now(&self) -> MonoTime {
+        MonoTime::now()
+    }
+
+    fn system_now(&self) -> Time {
+        SystemClock.now()
+    }
+}
+
+// The number of nanoseconds per tick is stored as a 32.32 fixed point number.
+const RATE_SHIFT: u32 = 32;
+
+fn rate(ns: i64, ticks: u64) -> Option<u64> {
+    if ns <= 0 || ticks == 0 {
+        return None;
+    }
+    Some((((ns as u128) << RATE_SHIFT) / ticks as u128) as u64)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TscClockError {
+    // The elapsed monotonic time and ticks during the calibration, the rate cannot
+    // be computed when either of them is not positive.
+    CalibrationFailed(Span, u64),
+}
+
+impl std::fmt::Display for TscClockError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl std::error::Error for TscClockError {}
+
+// The samples used to compute the rate at the next resynchronisation.
+struct Sample {
+    ticks: u64,
+    mono: MonoTime,
+}
+
+/// A clock reading the CPU timestamp counter (TSC) on x86_64, and converting it to
+/// a time using a rate calibrated against the monotonic clock. Reading the
+/// counter only takes a few nanoseconds. The clock is
+/// resynchronised with the system clock once `resync_interval` has elapsed, so
+/// the returned times can jump by the drift accumulated since the last
+/// resynchronisation. This assumes an invariant TSC, which is the case on most
+/// recent x86_64 processors.
+pub struct TscClock<S: TscSource = HardwareTscSource> {
+    // The calibration fields are protected by a sequence lock: `seq` is odd while
+    // the fields are updated and readers retry in this case.
+    seq: AtomicU64,
+    base_ticks: AtomicU64,
+    base_ns: AtomicI64,
+    rate: AtomicU64,
+    resync_interval: Span,
+    last_sample: Mutex<Sample>,
+    source: S,
+}
+
+impl<S: TscSource> std::fmt::Debug for TscClock<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TscClock").field("resync_interval", &self.resync_interval).finish()
+    }
+}
+
+impl TscClock {
+    /// Creates a clock, this blocks for roughly 10ms to calibrate the tick rate and
+    /// fails if the counter did not move during the calibration.
+    pub fn new(resync_interval: Span) -> Result<Self, TscClockError> {
+        Self::with_source(HardwareTscSource, resync_interval)
+    }
+}
+
+impl<S: TscSource> TscClock<S> {
+    /// Similar to `new` but reads the counter and clocks from `source`.
+    pub fn with_source(source: S, resync_interval: Span) -> Result<Self, TscClockError> {
+        let (ticks0, mono0) = (source.ticks(), source.mono_now());
+        source.sleep(Span::MS * 10);
+        let (ticks1, mono1) = (source.ticks(), source.mono_now());
+        let (elapsed, elapsed_ticks) = (mono1 - mono0, ticks1.wrapping_sub(ticks0));
+        let rate = rate(elapsed.to_int_ns(), elapsed_ticks)
+            .ok_or(TscClockError::CalibrationFailed(elapsed, elapsed_ticks))?;
+        let time = source.system_now();
+        let base_ticks = source.ticks();
+        Ok(Self {
+            seq: AtomicU64::new(0),
+            base_ticks: AtomicU64::new(base_ticks),
+            base_ns: AtomicI64::new(time.to_int_ns_since_epoch()),
+            rate: AtomicU64::new(rate),
+            resync_interval,
+            last_sample: Mutex::new(Sample { ticks: ticks0, mono: mono0 }),
+            source,
+        })
+    }
+
+    pub fn resync_interval(&self) -> Span {
+        self.resync_interval
+    }
+
+    // Returns the base ticks, base time, and rate.
+    fn calibration(&self) -> (u64, i64, u64) {
+        loop {
+            let seq = self.seq.load(Ordering::Acquire);
+            if seq % 2 == 1 {
+                std::hint::spin_loop();
+                continue;
+            }
+            let base_ticks = self.base_ticks.load(Ordering::Relaxed);
+            let base_ns = self.base_ns.load(Ordering::Relaxed);
+            let rate = self.rate.load(Ordering::Relaxed);
+            fence(Ordering::Acquire);
+            if self.seq.load(Ordering::Relaxed) == seq {
+                return (base_ticks, base_ns, rate);
+            }
+        }
+    }
+
+    /// Resynchronises this clock with the system clock and refines the tick rate
+    /// using the monotonic clock. This is done automatically by `now` once the
+    /// resynchronisation interval has elapsed.
+    pub fn resync(&self) {
+        // Only one thread resynchronises, the others keep on using the current
+        // calibration in the meantime.
+        let mut last_sample = match self.last_sample.try_lock() {
+            Ok(last_sample) => last_sample,
+            Err(_) => return,
+        };
+        let (ticks, mono) = (self.source.ticks(), self.source.mono_now());
+        let time = self.source.system_now();
+        let new_rate =
+            rate((mono - last_sample.mono).to_int_ns(), ticks.wrapping_sub(last_sample.ticks));
+        *last_sample = Sample { ticks, mono };
+        let seq = self.seq.load(Ordering::Relaxed);
+        self.seq.store(seq + 1, Ordering::Relaxed);
+        fence(Ordering::Release);
+        self.base_ticks.store(ticks, Ordering::Relaxed);
+        self.base_ns.store(time.to_int_ns_since_epoch(), Ordering::Relaxed);
+        if let Some(new_rate) = new_rate {
+            self.rate.store(new_rate, Ordering::Relaxed);
+        }
+        self.seq.store(seq + 2, Ordering::Release);
+    }
+
+    fn now_with_calibration(&self) -> (Time, bool) {
+        let (base_ticks, base_ns, rate) = self.calibration();
+        // The counter can be slightly behind the base on another core.
+        let elapsed_ticks = self.source.ticks().saturating_sub(base_ticks);
+        let elapsed_ns = ((elapsed_ticks as u128 * rate as u128) >> RATE_SHIFT) as i64;
+        let needs_resync = elapsed_ns >= self.resync_interval.to_int_ns();
+        (Time::of_int_ns_since_epoch(base_ns + elapsed_ns), needs_resync)
+    }
+}
+
+impl<S: TscSource> Clock for TscClock<S> {
+    fn now(&self) -> Time {
+        let (time, needs_resync) = self.now_with_calibration();
+        if needs_resync {
+            self.resync();
+            self.now_with_calibration().0
+        } else {
+            time
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{Date, OfDay, Time, TimeParseError, Tz, TzError};
+use std::str::FromStr;
+
+/// A time of day bound to a timezone, e.g. "09:30:00 America/New_York".
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ZonedOfDay {
+    ofday: OfDay,
+    tz: Tz,
+}
+
+impl ZonedOfDay {
+    pub fn new(ofday: OfDay, tz: Tz) -> Self {
+        Self { ofday, tz }
+    }
+
+    pub const fn ofday(self) -> OfDay {
+        self.ofday
+    }
+
+    pub const fn tz(self) -> Tz {
+        self.tz
+    }
+
+    /// The time at which this time of day happens on the given date, this fails
+    /// if the local time is skipped or repeated on this date.
+    pub fn to_time(self, date: Date) -> Result<Time, TzError> {
+        Time::of_date_ofday(date, self.ofday, self.tz)
+    }
+
+    /// Similar to `to_time` but always returns a time, see `Time::of_date_ofday_lenient`.
+    pub fn to_time_lenient(self, date: Date) -> Time {
+        Time::of_date_ofday_lenient(date, self.ofday, self.tz)
+    }
+
+    /// The first time strictly after `after` at which this time of day happens.
+    /// Dates on which the local time is skipped because of a daylight saving
+    /// change are ignored, when it is repeated both occurrences are considered.
+    /// Returns `None` if there is no such time before the last days of the
+    /// `Time` range.
+    pub fn next_occurrence(self, after: Time) -> Option<Time> {
+        let (last, _) = Time::MAX.to_date_ofday_gmt();
+        // Local dates are at most a day before the GMT one.
+        let mut date = after.to_date_ofday_gmt().0.saturating_add(-1);
+        while date <= last {
+            if Time::date_in_range(date) {
+                let candidates = match self.to_time(date) {
+                    Ok(time) => [Some(time), None],
+                    Err(TzError::TwoTimesInThisTz(t1, t2)) => [Some(t1), Some(t2)],
+                    Err(TzError::NoTimeInThisTz) => [None, None],
+                };
+                if let Some(time) = candidates.into_iter().flatten().find(|&t| t > after) {
+                    return Some(time);
+                }
+            }
+            date += 1;
+        }
+        None
+    }
+}
+
+impl std::fmt::Display for ZonedOfDay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.ofday, self.tz)
+    }
+}
+
+impl std::fmt::Debug for ZonedOfDay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl FromStr for ZonedOfDay {
+    type Err = TimeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (ofday, tz) = s.split_once(' ').ok_or(TimeParseError::NoZone)?;
+        Ok(Self::new(OfDay::from_str(ofday)?, Tz::from_str(tz)?))
+    }
+}
+
+// The time of day followed by the zone name, i.e. the layout of a
+// `{ ofday; zone }` record where zones are written as their name.
+#[cfg(feature = "binio")]
+mod binio {
+    use super::ZonedOfDay;
+    use crate::{OfDay, Tz};
+    use std::str::FromStr;
+
+    impl binprot::BinProtRead for ZonedOfDay {
+        fn binprot_read<R>(r: &mut R) -> Result<Self, binprot::Error>
+        where
+            R: std::io::Read + ?Sized,
+        {
+            let ofday: OfDay = binprot::BinProtRead::binprot_read(r)?;
+            let tz: String = binprot::BinProtRead::binprot_read(r)?;
+            let tz = Tz::from_str(&tz).map_err(|err| binprot::Error::CustomError(Box::new(err)))?;
+            Ok(Self::new(ofday, tz))
+        }
+    }
+
+    impl binprot::BinProtWrite for ZonedOfDay {
+        fn binprot_write<W>(&self, w: &mut W) -> Result<(), std::io::Error>
+        where
+            W: std::io::Write,
+        {
+            binprot::BinProtWrite::binprot_write(&self.ofday, w)?;
+            binprot::BinProtWrite::binprot_write(&self.tz.name().to_string(), w)
+        }
+    }
+}
+
+#[cfg(feature = "sexp")]
+mod sexp {
+    use super::ZonedOfDay;
+    use crate::{OfDay, Tz};
+    use rsexp::Sexp;
+    use std::str::FromStr;
+
+    // A list with the time of day and the zone name, e.g. (09:30:00 America/New_York).
+    impl rsexp::SexpOf for ZonedOfDay {
+        fn sexp_of(&self) -> Sexp {
+            rsexp::SexpOf::sexp_of(&(self.ofday, self.tz.name().to_string()))
+        }
+    }
+
+    impl rsexp::OfSexp for ZonedOfDay {
+        fn of_sexp(sexp: &Sexp) -> Result<Self, rsexp::IntoSexpError> {
+            let (ofday, tz): (OfDay, String) = rsexp::OfSexp::of_sexp(sexp)?;
+            let tz = Tz::from_str(&tz).map_err(|err| {
+                rsexp::IntoSexpError::StringConversionError { err: err.to_string() }
+            })?;
+            Ok(ZonedOfDay::new(ofday, tz))
+        }
+    }
+}
+
+#[cfg(feature = "with_serde")]
+mod with_serde {
+    use super::ZonedOfDay;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::str::FromStr;
+
+    impl Serialize for ZonedOfDay {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.to_string().serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for ZonedOfDay {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let s = String::deserialize(deserializer)?;
+            ZonedOfDay::from_str(&s).map_err(serde::de::Error::custom)
+        }
+    }
+}
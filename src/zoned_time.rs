@@ -0,0 +1,407 @@
+use crate::time::{parse_zone_offset, write_zone_offset};
+use crate::{Date, DateTime, DayOfWeek, OfDay, Span, Time, TimeParseError, Tz, TzError};
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::str::FromStr;
+
+/// A fixed offset from GMT in seconds, e.g. 19800 for +05:30, this is at most
+/// 18 hours in either direction.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct FixedOffset(i32);
+
+impl FixedOffset {
+    pub const MAX_SEC: i32 = 18 * 3600;
+
+    /// Returns `None` if the offset is more than 18 hours in either direction.
+    pub const fn of_sec(offset_sec: i32) -> Option<Self> {
+        if -Self::MAX_SEC <= offset_sec && offset_sec <= Self::MAX_SEC {
+            Some(Self(offset_sec))
+        } else {
+            None
+        }
+    }
+
+    pub const fn to_sec(self) -> i32 {
+        self.0
+    }
+}
+
+/// A timezone from the tz database, or a fixed offset from GMT.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Zone {
+    Tz(Tz),
+    Fixed(FixedOffset),
+}
+
+impl Zone {
+    pub const UTC: Self = Self::Fixed(FixedOffset(0));
+
+    /// A fixed offset from GMT in seconds, returns `None` if the offset is more
+    /// than 18 hours in either direction.
+    pub const fn fixed(offset_sec: i32) -> Option<Self> {
+        match FixedOffset::of_sec(offset_sec) {
+            Some(offset) => Some(Self::Fixed(offset)),
+            None => None,
+        }
+    }
+
+    fn offset_sec(self, time: Time) -> i32 {
+        match self {
+            Self::Tz(tz) => tz.tz_info().find(time).total_offset_sec(),
+            Self::Fixed(offset) => offset.to_sec(),
+        }
+    }
+
+    /// The offset from GMT at the given time.
+    pub fn offset(self, time: Time) -> Span {
+        Span::of_int_sec(self.offset_sec(time) as i64)
+    }
+
+    /// The time at which the local date and time of day are as specified, this
+    /// fails for timezones where this local time is skipped or repeated.
+    pub fn to_time(self, date: Date, ofday: OfDay) -> Result<Time, TzError> {
+        match self {
+            Self::Tz(tz) => Time::of_date_ofday(date, ofday, tz),
+            Self::Fixed(_) => Ok(self.to_time_lenient(date, ofday)),
+        }
+    }
+
+    /// Similar to `to_time` but always returns a time, see `Time::of_date_ofday_lenient`.
+    pub fn to_time_lenient(self, date: Date, ofday: OfDay) -> Time {
+        match self {
+            Self::Tz(tz) => Time::of_date_ofday_lenient(date, ofday, tz),
+            Self::Fixed(offset) => {
+                Time::of_date_ofday_gmt(date, ofday) - Span::of_int_sec(offset.to_sec() as i64)
+            }
+        }
+    }
+}
+
+impl From<Tz> for Zone {
+    fn from(tz: Tz) -> Self {
+        Self::Tz(tz)
+    }
+}
+
+impl std::fmt::Display for Zone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Tz(tz) => write!(f, "{tz}"),
+            Self::Fixed(offset) => write_zone_offset(f, offset.to_sec()),
+        }
+    }
+}
+
+impl std::fmt::Debug for Zone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+// Parses "Z", "+05:30", or "-04:00", offsets of more than 18 hours are rejected.
+fn parse_offset(s: &str) -> Result<FixedOffset, TimeParseError> {
+    let offset = match s.split_at_checked(1) {
+        Some(("Z", "")) => Span::ZERO,
+        Some(("+", offset)) => parse_zone_offset(offset)?,
+        Some(("-", offset)) => -parse_zone_offset(offset)?,
+        _ => Err(TimeParseError::NoZone)?,
+    };
+    i32::try_from(offset.to_int_ns() / Span::SEC.to_int_ns())
+        .ok()
+        .and_then(FixedOffset::of_sec)
+        .ok_or(TimeParseError::InvalidZoneOffset(offset))
+}
+
+impl FromStr for Zone {
+    type Err = TimeParseError;
+
+    // Accepts timezone names such as "Europe/London" and offsets such as "+05:30".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with(['Z', '+', '-']) {
+            Ok(Self::Fixed(parse_offset(s)?))
+        } else {
+            Ok(Self::Tz(Tz::from_str(s)?))
+        }
+    }
+}
+
+/// A time together with the zone used to express it in local terms. The string
+/// representation includes the zone, e.g. "2021-01-01 10:00:00 Europe/London" or
+/// "2021-01-01 15:30:00+05:30". The offset is also included for timezones when
+/// the local time is ambiguous, e.g. "2021-10-31 01:30:00+01:00 Europe/London".
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ZonedTime {
+    time: Time,
+    zone: Zone,
+}
+
+impl ZonedTime {
+    pub fn new(time: Time, zone: Zone) -> Self {
+        Self { time, zone }
+    }
+
+    pub fn now(zone: Zone) -> Self {
+        Self::new(Time::now(), zone)
+    }
+
+    pub fn of_date_ofday(date: Date, ofday: OfDay, zone: Zone) -> Result<Self, TzError> {
+        Ok(Self::new(zone.to_time(date, ofday)?, zone))
+    }
+
+    pub fn of_date_ofday_lenient(date: Date, ofday: OfDay, zone: Zone) -> Self {
+        Self::new(zone.to_time_lenient(date, ofday), zone)
+    }
+
+    pub const fn time(self) -> Time {
+        self.time
+    }
+
+    pub const fn zone(self) -> Zone {
+        self.zone
+    }
+
+    /// The same time expressed in another zone.
+    pub fn with_zone(self, zone: Zone) -> Self {
+        Self::new(self.time, zone)
+    }
+
+    /// The offset from GMT in effect at this time.
+    pub fn offset(self) -> Span {
+        self.zone.offset(self.time)
+    }
+
+    /// The local date and time of day.
+    pub fn date_time(self) -> DateTime {
+        let local = self.time + self.offset();
+        DateTime::of_time_gmt(local)
+    }
+
+    pub fn date(self) -> Date {
+        self.date_time().date()
+    }
+
+    pub fn ofday(self) -> OfDay {
+        self.date_time().ofday()
+    }
+
+    pub fn day_of_week(self) -> DayOfWeek {
+        self.date().day_of_week()
+    }
+
+    // Moves the local date keeping the local time of day, local times that do not
+    // exist or are ambiguous after a daylight saving change are resolved as per
+    // `Time::of_date_ofday_lenient`.
+    fn with_date(self, date: Date) -> Self {
+        Self::of_date_ofday_lenient(date, self.ofday(), self.zone)
+    }
+
+    /// Adds some calendar days, keeping the same local time of day. This differs
+    /// from adding `Span::DAY` multiples when crossing a daylight saving change.
+    pub fn add_days(self, n: i32) -> Self {
+        self.with_date(self.date() + n)
+    }
+
+    /// Adds some calendar months keeping the same local time of day, the day is
+    /// clamped to the end of the month as per `Date::add_months`.
+    pub fn add_months(self, m: i32) -> Self {
+        self.with_date(self.date().add_months(m))
+    }
+
+    pub fn add_years(self, y: i32) -> Self {
+        self.with_date(self.date().add_years(y))
+    }
+}
+
+impl Time {
+    pub fn to_zoned(self, zone: Zone) -> ZonedTime {
+        ZonedTime::new(self, zone)
+    }
+}
+
+impl From<ZonedTime> for Time {
+    fn from(zoned: ZonedTime) -> Self {
+        zoned.time
+    }
+}
+
+impl std::fmt::Display for ZonedTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let offset_sec = self.zone.offset_sec(self.time);
+        let (date, ofday) = (self.date(), self.ofday());
+        write!(f, "{date} {ofday}")?;
+        match self.zone {
+            Zone::Fixed(_) => write_zone_offset(f, offset_sec),
+            Zone::Tz(tz) => {
+                if tz.tz_info().date_ofday_to_time(date, ofday).is_err() {
+                    write_zone_offset(f, offset_sec)?;
+                }
+                write!(f, " {tz}")
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for ZonedTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl FromStr for ZonedTime {
+    type Err = TimeParseError;
+
+    // The time of day can be followed by an offset, a zone, or both in which case
+    // they have to be consistent.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (date, rest) = s.split_once([' ', 'T']).ok_or(TimeParseError::NoSpace)?;
+        let date = Date::from_str(date)?;
+        let (ofday, zone) = match rest.split_once(' ') {
+            Some((ofday, zone)) => (ofday, Some(Zone::from_str(zone)?)),
+            None => (rest, None),
+        };
+        let (ofday, offset) = match ofday.find(['Z', '+', '-']) {
+            Some(index) => (&ofday[..index], Some(parse_offset(&ofday[index..])?)),
+            None => (ofday, None),
+        };
+        let ofday = OfDay::from_str(ofday)?;
+        match (zone, offset) {
+            (None, None) => Err(TimeParseError::NoZone),
+            (Some(zone), None) => Ok(Self::of_date_ofday(date, ofday, zone)?),
+            (zone, Some(offset)) => {
+                let zone = zone.unwrap_or(Zone::Fixed(offset));
+                let time = Zone::Fixed(offset).to_time_lenient(date, ofday);
+                let zone_offset = zone.offset(time);
+                if zone_offset != Span::of_int_sec(offset.to_sec() as i64) {
+                    return Err(TimeParseError::ZoneOffsetMismatch(zone_offset));
+                }
+                Ok(Self::new(time, zone))
+            }
+        }
+    }
+}
+
+impl Add<Span> for ZonedTime {
+    type Output = Self;
+
+    fn add(self, other: Span) -> Self {
+        Self::new(self.time + other, self.zone)
+    }
+}
+
+impl AddAssign<Span> for ZonedTime {
+    fn add_assign(&mut self, other: Span) {
+        self.time += other
+    }
+}
+
+impl Sub<Span> for ZonedTime {
+    type Output = Self;
+
+    fn sub(self, other: Span) -> Self {
+        Self::new(self.time - other, self.zone)
+    }
+}
+
+impl SubAssign<Span> for ZonedTime {
+    fn sub_assign(&mut self, other: Span) {
+        self.time -= other
+    }
+}
+
+impl Sub for ZonedTime {
+    type Output = Span;
+
+    fn sub(self, other: Self) -> Span {
+        self.time - other.time
+    }
+}
+
+#[cfg(feature = "sexp")]
+mod sexp {
+    use super::ZonedTime;
+    use rsexp::Sexp;
+
+    // Similar to the `Time` format with the zone name as an additional atom, e.g.
+    // (2021-01-01 10:00:00 Europe/London) or (2021-01-01 15:30:00+05:30).
+    impl rsexp::SexpOf for ZonedTime {
+        fn sexp_of(&self) -> Sexp {
+            let atoms =
+                self.to_string().split(' ').map(|s| Sexp::Atom(s.as_bytes().to_vec())).collect();
+            Sexp::List(atoms)
+        }
+    }
+
+    impl rsexp::OfSexp for ZonedTime {
+        fn of_sexp(sexp: &Sexp) -> Result<Self, rsexp::IntoSexpError> {
+            match sexp {
+                Sexp::List(list) if list.len() == 2 || list.len() == 3 => {
+                    let mut atoms = vec![];
+                    for sexp in list {
+                        match sexp {
+                            Sexp::Atom(atom) => atoms.push(String::from_utf8_lossy(atom)),
+                            Sexp::List(list) => {
+                                return Err(rsexp::IntoSexpError::ExpectedAtomGotList {
+                                    type_: "zoned_time",
+                                    list_len: list.len(),
+                                })
+                            }
+                        }
+                    }
+                    atoms.join(" ").parse().map_err(|err: crate::TimeParseError| {
+                        rsexp::IntoSexpError::StringConversionError { err: err.to_string() }
+                    })
+                }
+                Sexp::List(list) => Err(rsexp::IntoSexpError::ListLengthMismatch {
+                    type_: "zoned_time",
+                    list_len: list.len(),
+                    expected_len: 3,
+                }),
+                Sexp::Atom(_) => {
+                    Err(rsexp::IntoSexpError::ExpectedListGotAtom { type_: "zoned_time" })
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "with_serde")]
+mod with_serde {
+    use super::{Zone, ZonedTime};
+    use crate::Time;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::str::FromStr;
+
+    impl Serialize for Zone {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.to_string().serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Zone {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let s = String::deserialize(deserializer)?;
+            Zone::from_str(&s).map_err(serde::de::Error::custom)
+        }
+    }
+
+    impl Serialize for ZonedTime {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                self.to_string().serialize(serializer)
+            } else {
+                (self.time, self.zone).serialize(serializer)
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for ZonedTime {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            if deserializer.is_human_readable() {
+                let s = String::deserialize(deserializer)?;
+                ZonedTime::from_str(&s).map_err(serde::de::Error::custom)
+            } else {
+                let (time, zone) = <(Time, Zone)>::deserialize(deserializer)?;
+                Ok(ZonedTime::new(time, zone))
+            }
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use std::str::FromStr;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::sync::Arc;
+use timens::{
+    with_clock, Clock, CoarseClock, Date, FixedClock, ManualClock, MonoTime, Span, SystemClock,
+    Time, TscClock, TscClockError, TscSource, Tz,
+};
+
+#[test]
+fn clocks() {
+    let time = Time::from_str("2021-01-16 23:30:00Z").unwrap();
+    let clock = FixedClock(time);
+    assert_eq!(clock.now(), time);
+    assert_eq!(clock.today(Tz::GMT), Date::from_str("2021-01-16").unwrap());
+    assert_eq!(clock.today(Tz::Asia__Hong_Kong), Date::from_str("2021-01-17").unwrap());
+
+    let clock = ManualClock::new(time);
+    clock.advance(Span::HR);
+    assert_eq!(clock.now(), time + Span::HR);
+    clock.advance(-Span::MIN);
+    assert_eq!(clock.now(), time + Span::MIN * 59);
+    clock.set(Time::EPOCH);
+    assert_eq!(clock.now(), Time::EPOCH);
+    let clocks: Vec<Box<dyn Clock>> = vec![Box::new(FixedClock(time)), Box::new(&clock)];
+    assert_eq!(clocks.iter().map(|c| c.now()).collect::<Vec<_>>(), [time, Time::EPOCH]);
+
+    let before = SystemClock.now();
+    assert!(Time::now() >= before);
+}
+
+#[test]
+fn with_clock_override() {
+    let time = Time::from_str("2021-01-16 23:30:00Z").unwrap();
+    let res = with_clock(FixedClock(time), || {
+        assert_eq!(Time::now(), time);
+        assert_eq!(Date::today(Tz::GMT), Date::from_str("2021-01-16").unwrap());
+        // Nested overrides take precedence and are restored on exit.
+        with_clock(FixedClock(Time::EPOCH), || assert_eq!(Time::now(), Time::EPOCH));
+        // Other threads are not affected.
+        let other = std::thread::spawn(Time::now).join().unwrap();
+        assert!(other > time);
+        Time::now()
+    });
+    assert_eq!(res, time);
+    assert!(Time::now() > time);
+
+    let clock = Arc::new(ManualClock::new(time));
+    with_clock(clock.clone(), || {
+        assert_eq!(Time::now(), time);
+        clock.advance(Span::HR);
+        assert_eq!(Time::now(), time + Span::HR);
+        assert_eq!(Date::today(Tz::GMT), Date::from_str("2021-01-17").unwrap());
+    });
+
+    // The previous clock is restored when the closure panics.
+    let res = std::panic::catch_unwind(|| with_clock(FixedClock(time), || panic!("oops")));
+    assert!(res.is_err());
+    assert!(Time::now() > time);
+}
+
+// A simulated counter and clocks, the counter runs at `ticks_per_ns` and the
+// system clock can be stepped independently of the monotonic clock.
+struct SimulatedSource {
+    ticks: AtomicU64,
+    ticks_per_ns: AtomicU64,
+    mono_ns: AtomicI64,
+    system_ns: AtomicI64,
+}
+
+impl SimulatedSource {
+    fn new(time: Time, ticks_per_ns: u64) -> Self {
+        Self {
+            ticks: AtomicU64::new(1_000),
+            ticks_per_ns: AtomicU64::new(ticks_per_ns),
+            mono_ns: AtomicI64::new(0),
+            system_ns: AtomicI64::new(time.to_int_ns_since_epoch()),
+        }
+    }
+
+    fn advance(&self, span: Span) {
+        let ticks_per_ns = self.ticks_per_ns.load(Ordering::SeqCst);
+        self.ticks.fetch_add(span.to_int_ns() as u64 * ticks_per_ns, Ordering::SeqCst);
+        self.mono_ns.fetch_add(span.to_int_ns(), Ordering::SeqCst);
+        self.system_ns.fetch_add(span.to_int_ns(), Ordering::SeqCst);
+    }
+}
+
+impl TscSource for SimulatedSource {
+    fn ticks(&self) -> u64 {
+        self.ticks.load(Ordering::SeqCst)
+    }
+
+    fn mono_now(&self) -> MonoTime {
+        MonoTime::of_int_ns(self.mono_ns.load(Ordering::SeqCst))
+    }
+
+    fn system_now(&self) -> Time {
+        Time::of_int_ns_since_epoch(self.system_ns.load(Ordering::SeqCst))
+    }
+
+    fn sleep(&self, span: Span) {
+        self.advance(span)
+    }
+}
+
+#[test]
+fn tsc_clock_calibration() {
+    let start = Time::from_str("2021-01-16 23:30:00Z").unwrap();
+    let source = SimulatedSource::new(start, 2);
+    let clock = TscClock::with_source(&source, Span::MS * 20).unwrap();
+    assert_eq!(clock.resync_interval(), Span::MS * 20);
+    // The calibration waits for 10ms.
+    let base = start + Span::MS * 10;
+    assert_eq!(clock.now(), base);
+    source.advance(Span::MS * 5);
+    assert_eq!(clock.now(), base + Span::MS * 5);
+
+    // Steps of the system clock are only picked up when resynchronising.
+    source.system_ns.fetch_add(Span::SEC.to_int_ns(), Ordering::SeqCst);
+    source.advance(Span::MS * 10);
+    assert_eq!(clock.now(), base + Span::MS * 15);
+    source.advance(Span::MS * 5);
+    assert_eq!(clock.now(), base + Span::SEC + Span::MS * 20);
+
+    // A change of frequency is picked up by the rate at the next resynchronisation.
+    source.ticks_per_ns.store(4, Ordering::SeqCst);
+    source.advance(Span::MS * 5);
+    assert_eq!(clock.now(), base + Span::SEC + Span::MS * 30);
+    clock.resync();
+    assert_eq!(clock.now(), base + Span::SEC + Span::MS * 25);
+    source.advance(Span::MS * 10);
+    assert_eq!(clock.now(), source.system_now());
+}
+
+#[test]
+fn tsc_clock_calibration_failure() {
+    struct StuckSource(SimulatedSource);
+
+    impl TscSource for StuckSource {
+        fn ticks(&self) -> u64 {
+            42
+        }
+
+        fn mono_now(&self) -> MonoTime {
+            self.0.mono_now()
+        }
+
+        fn system_now(&self) -> Time {
+            self.0.system_now()
+        }
+
+        fn sleep(&self, span: Span) {
+            self.0.advance(span)
+        }
+    }
+
+    let source = StuckSource(SimulatedSource::new(Time::EPOCH, 1));
+    let res = TscClock::with_source(source, Span::MS * 20);
+    assert_eq!(res.err(), Some(TscClockError::CalibrationFailed(Span::MS * 10, 0)));
+}
+
+#[test]
+fn low_overhead_clocks() {
+    let before = SystemClock.now();
+    let coarse = CoarseClock.now();
+    // The coarse clock resolution is the kernel tick.
+    assert!((coarse - before).abs() < Span::MS * 50);
+    assert!(CoarseClock.now() >= coarse);
+
+    // Smoke check against the real counter, the calibration is covered by the
+    // simulated source above.
+    let clock = TscClock::new(Span::MS * 20).unwrap();
+    assert!((clock.now() - SystemClock.now()).abs() < Span::SEC);
+}
@@ -1,5 +1,5 @@
 use std::str::FromStr;
-use timens::{Date, DayOfWeek, Month};
+use timens::{Date, DateError, DayOfWeek, Inclusivity, Month};
 
 #[test]
 fn add_sub() {
@@ -182,3 +182,342 @@ fn sexp_roundtrip() {
     let rt: Date = rsexp::OfSexp::of_sexp(&sexp).unwrap();
     assert_eq!(d, rt)
 }
+
+#[test]
+fn iso_week() {
+    let check = |date: &str, year: i32, week: u8, day_of_week: DayOfWeek| {
+        let date = Date::from_str(date).unwrap();
+        assert_eq!(date.iso_week_date(), (year, week, day_of_week), "{date}");
+        assert_eq!(Date::of_iso_week(year, week, day_of_week), Ok(date));
+    };
+    // Examples from https://en.wikipedia.org/wiki/ISO_week_date
+    check("2005-01-01", 2004, 53, DayOfWeek::Sat);
+    check("2005-01-02", 2004, 53, DayOfWeek::Sun);
+    check("2005-12-31", 2005, 52, DayOfWeek::Sat);
+    check("2006-01-01", 2005, 52, DayOfWeek::Sun);
+    check("2006-01-02", 2006, 1, DayOfWeek::Mon);
+    check("2007-12-30", 2007, 52, DayOfWeek::Sun);
+    check("2007-12-31", 2008, 1, DayOfWeek::Mon);
+    check("2008-12-28", 2008, 52, DayOfWeek::Sun);
+    check("2008-12-29", 2009, 1, DayOfWeek::Mon);
+    check("2009-12-31", 2009, 53, DayOfWeek::Thu);
+    check("2010-01-03", 2009, 53, DayOfWeek::Sun);
+    check("2021-01-03", 2020, 53, DayOfWeek::Sun);
+    check("0000-01-01", -1, 52, DayOfWeek::Sat);
+    check("0000-01-03", 0, 1, DayOfWeek::Mon);
+    check("9999-12-31", 9999, 52, DayOfWeek::Fri);
+    assert_eq!(
+        Date::of_iso_week(2020, 54, DayOfWeek::Mon),
+        Err(DateError::InvalidIsoWeek(2020, 54))
+    );
+    assert_eq!(
+        Date::of_iso_week(2021, 53, DayOfWeek::Mon),
+        Err(DateError::InvalidIsoWeek(2021, 53))
+    );
+    assert_eq!(Date::of_iso_week(2021, 0, DayOfWeek::Mon), Err(DateError::InvalidIsoWeek(2021, 0)));
+    // Only the last two days of week-numbering year -1 are in range.
+    assert_eq!(Date::of_iso_week(-1, 52, DayOfWeek::Fri), Err(DateError::InvalidIsoWeek(-1, 52)));
+    assert_eq!(
+        Date::of_iso_week(-1, 52, DayOfWeek::Sun),
+        Ok(Date::from_str("0000-01-02").unwrap())
+    );
+    assert_eq!(Date::of_iso_week(-2, 1, DayOfWeek::Mon), Err(DateError::InvalidIsoYear(-2)));
+    assert_eq!(
+        Date::of_iso_week(9999, 52, DayOfWeek::Sat),
+        Err(DateError::InvalidIsoWeek(9999, 52))
+    );
+    assert_eq!(Date::of_iso_week(-5, 1, DayOfWeek::Mon), Err(DateError::InvalidIsoYear(-5)));
+    assert_eq!(
+        Date::of_iso_week(10_000_000, 1, DayOfWeek::Mon),
+        Err(DateError::InvalidIsoYear(10_000_000))
+    );
+    assert_eq!(
+        Date::of_iso_week(i32::MIN, 1, DayOfWeek::Mon),
+        Err(DateError::InvalidIsoYear(i32::MIN))
+    );
+    assert_eq!(
+        Date::of_iso_week(10_000, 1, DayOfWeek::Mon),
+        Err(DateError::InvalidIsoYear(10_000))
+    );
+    assert_eq!(Date::of_iso_week(0, 1, DayOfWeek::Mon), Ok(Date::from_str("0000-01-03").unwrap()));
+    for date in Date::dates_between(
+        Date::from_str("1999-12-01").unwrap(),
+        Date::from_str("2030-01-31").unwrap(),
+    ) {
+        let (year, week, day_of_week) = date.iso_week_date();
+        assert_eq!(day_of_week, date.day_of_week());
+        assert_eq!(Date::of_iso_week(year, week, day_of_week), Ok(date));
+    }
+}
+
+#[test]
+fn ordinal() {
+    let check = |date: &str, day_of_year: u16, quarter: u8| {
+        let date = Date::from_str(date).unwrap();
+        assert_eq!(date.day_of_year(), day_of_year, "{date}");
+        assert_eq!(date.quarter(), quarter, "{date}");
+        assert_eq!(Date::of_ordinal(date.year(), day_of_year), Ok(date));
+    };
+    check("2021-01-01", 1, 1);
+    check("2021-03-31", 90, 1);
+    check("2021-04-01", 91, 2);
+    check("2021-12-31", 365, 4);
+    check("2020-02-29", 60, 1);
+    check("2020-12-31", 366, 4);
+    check("0000-01-01", 1, 1);
+    check("0000-02-29", 60, 1);
+    check("0000-03-01", 61, 1);
+    check("9999-12-31", 365, 4);
+    assert_eq!(Date::of_ordinal(2021, 366), Err(DateError::InvalidDayOfYear(2021, 366)));
+    assert_eq!(Date::of_ordinal(2021, 0), Err(DateError::InvalidDayOfYear(2021, 0)));
+    assert_eq!(Date::of_ordinal(10000, 1), Err(DateError::InvalidYear(10000)));
+    assert_eq!(
+        Date::create(0, Month::Feb, 29).unwrap() + 1,
+        Date::create(0, Month::Mar, 1).unwrap()
+    );
+    assert_eq!(Date::create(0, Month::Jan, 1).unwrap().day_of_week(), DayOfWeek::Sat);
+}
+
+#[test]
+fn relative_helpers() {
+    let d = |s: &str| Date::from_str(s).unwrap();
+    let date = d("2021-05-12");
+    assert_eq!(date.day_of_week(), DayOfWeek::Wed);
+    assert_eq!(date.first_day_of_month(), d("2021-05-01"));
+    assert_eq!(date.last_day_of_month(), d("2021-05-31"));
+    assert_eq!(date.first_day_of_quarter(), d("2021-04-01"));
+    assert_eq!(date.last_day_of_quarter(), d("2021-06-30"));
+    assert_eq!(date.first_day_of_year(), d("2021-01-01"));
+    assert_eq!(date.last_day_of_year(), d("2021-12-31"));
+    assert_eq!(d("2020-02-10").last_day_of_month(), d("2020-02-29"));
+    assert_eq!(d("2021-02-10").last_day_of_month(), d("2021-02-28"));
+    assert_eq!(d("2021-12-10").first_day_of_quarter(), d("2021-10-01"));
+    assert_eq!(d("2021-12-10").last_day_of_quarter(), d("2021-12-31"));
+
+    assert_eq!(date.next_weekday(DayOfWeek::Wed, Inclusivity::Inclusive), Some(date));
+    assert_eq!(date.next_weekday(DayOfWeek::Wed, Inclusivity::Exclusive), Some(d("2021-05-19")));
+    assert_eq!(date.next_weekday(DayOfWeek::Mon, Inclusivity::Inclusive), Some(d("2021-05-17")));
+    assert_eq!(date.next_weekday(DayOfWeek::Thu, Inclusivity::Exclusive), Some(d("2021-05-13")));
+    assert_eq!(date.prev_weekday(DayOfWeek::Wed, Inclusivity::Inclusive), Some(date));
+    assert_eq!(date.prev_weekday(DayOfWeek::Wed, Inclusivity::Exclusive), Some(d("2021-05-05")));
+    assert_eq!(date.prev_weekday(DayOfWeek::Mon, Inclusivity::Exclusive), Some(d("2021-05-10")));
+    assert_eq!(date.prev_weekday(DayOfWeek::Thu, Inclusivity::Inclusive), Some(d("2021-05-06")));
+
+    assert_eq!(date.nth_weekday_of_month(3, DayOfWeek::Fri), Some(d("2021-05-21")));
+    assert_eq!(date.nth_weekday_of_month(1, DayOfWeek::Sat), Some(d("2021-05-01")));
+    assert_eq!(date.nth_weekday_of_month(5, DayOfWeek::Mon), Some(d("2021-05-31")));
+    assert_eq!(date.nth_weekday_of_month(5, DayOfWeek::Tue), None);
+    assert_eq!(date.nth_weekday_of_month(-1, DayOfWeek::Mon), Some(d("2021-05-31")));
+    assert_eq!(date.nth_weekday_of_month(-1, DayOfWeek::Fri), Some(d("2021-05-28")));
+    assert_eq!(date.nth_weekday_of_month(-5, DayOfWeek::Sat), Some(d("2021-05-01")));
+    assert_eq!(date.nth_weekday_of_month(-5, DayOfWeek::Fri), None);
+    assert_eq!(date.nth_weekday_of_month(0, DayOfWeek::Fri), None);
+    assert_eq!(date.nth_weekday_of_month(6, DayOfWeek::Fri), None);
+    // No panic at the ends of the supported range.
+    assert_eq!(Date::MAX.next_weekday(DayOfWeek::Mon, Inclusivity::Inclusive), None);
+    assert_eq!(Date::MAX.next_weekday(DayOfWeek::Fri, Inclusivity::Inclusive), Some(Date::MAX));
+    assert_eq!(Date::MAX.next_weekday(DayOfWeek::Fri, Inclusivity::Exclusive), None);
+    assert_eq!(Date::MIN.prev_weekday(DayOfWeek::Mon, Inclusivity::Inclusive), None);
+    assert_eq!(Date::MIN.prev_weekday(DayOfWeek::Sat, Inclusivity::Inclusive), Some(Date::MIN));
+    assert_eq!(Date::MIN.prev_weekday(DayOfWeek::Sat, Inclusivity::Exclusive), None);
+    assert_eq!(Date::MAX.nth_weekday_of_month(5, DayOfWeek::Sat), None);
+    assert_eq!(Date::MAX.nth_weekday_of_month(5, DayOfWeek::Fri), Some(Date::MAX));
+    assert_eq!(Date::MIN.nth_weekday_of_month(-5, DayOfWeek::Thu), None);
+    assert_eq!(Date::MIN.nth_weekday_of_month(-5, DayOfWeek::Sat), Some(Date::MIN));
+    // Last business day of the quarter.
+    assert_eq!(
+        d("2021-07-15").last_day_of_quarter().round_backward_to_business_day(|d| d.is_weekday()),
+        d("2021-09-30")
+    );
+    assert_eq!(
+        d("2021-02-15").last_day_of_quarter().round_backward_to_business_day(|d| d.is_weekday()),
+        d("2021-03-31")
+    );
+}
+
+// Easter dates for 2000-2025, as listed in https://en.wikipedia.org/wiki/List_of_dates_for_Easter
+const EASTER_TABLE: [(&str, &str); 26] = [
+    ("2000-04-23", "2000-04-30"),
+    ("2001-04-15", "2001-04-15"),
+    ("2002-03-31", "2002-05-05"),
+    ("2003-04-20", "2003-04-27"),
+    ("2004-04-11", "2004-04-11"),
+    ("2005-03-27", "2005-05-01"),
+    ("2006-04-16", "2006-04-23"),
+    ("2007-04-08", "2007-04-08"),
+    ("2008-03-23", "2008-04-27"),
+    ("2009-04-12", "2009-04-19"),
+    ("2010-04-04", "2010-04-04"),
+    ("2011-04-24", "2011-04-24"),
+    ("2012-04-08", "2012-04-15"),
+    ("2013-03-31", "2013-05-05"),
+    ("2014-04-20", "2014-04-20"),
+    ("2015-04-05", "2015-04-12"),
+    ("2016-03-27", "2016-05-01"),
+    ("2017-04-16", "2017-04-16"),
+    ("2018-04-01", "2018-04-08"),
+    ("2019-04-21", "2019-04-28"),
+    ("2020-04-12", "2020-04-19"),
+    ("2021-04-04", "2021-05-02"),
+    ("2022-04-17", "2022-04-24"),
+    ("2023-04-09", "2023-04-16"),
+    ("2024-03-31", "2024-05-05"),
+    ("2025-04-20", "2025-04-20"),
+];
+
+// Gauss' algorithm with Lichtenberg's corrections, returning the day of March
+// on which Easter falls (values above 31 being in April).
+fn easter_gauss(year: i64, julian: bool) -> i64 {
+    let k = year / 100;
+    let (m, s) = if julian {
+        (15, 0)
+    } else {
+        (15 + (3 * k + 3) / 4 - (8 * k + 13) / 25, 2 - (3 * k + 3) / 4)
+    };
+    let a = year % 19;
+    let d = (19 * a + m) % 30;
+    let r = (d + a / 11) / 29;
+    let og = 21 + d - r;
+    let sz = 7 - (year + year / 4 + s) % 7;
+    let oe = 7 - (og - sz) % 7;
+    og + oe
+}
+
+#[test]
+fn easter() {
+    for (western, orthodox) in EASTER_TABLE {
+        let western = Date::from_str(western).unwrap();
+        let orthodox = Date::from_str(orthodox).unwrap();
+        assert_eq!(Date::easter_western(western.year()), Ok(western));
+        assert_eq!(Date::easter_orthodox(orthodox.year()), Ok(orthodox));
+    }
+    assert_eq!(Date::easter_western(1818).unwrap().to_string(), "1818-03-22");
+    assert_eq!(Date::easter_western(1943).unwrap().to_string(), "1943-04-25");
+    assert_eq!(Date::easter_western(2285).unwrap().to_string(), "2285-03-22");
+    assert_eq!(Date::easter_western(10000), Err(DateError::InvalidYear(10000)));
+    assert_eq!(Date::easter_orthodox(10000), Err(DateError::InvalidYear(10000)));
+    for year in 0..=9999 {
+        let western = Date::easter_western(year).unwrap();
+        assert_eq!(western.day_of_week(), DayOfWeek::Sun);
+        let march_day = easter_gauss(year as i64, false);
+        let expected = Date::create(year, Month::Mar, 1).unwrap() + (march_day - 1) as i32;
+        assert_eq!(western, expected, "{year}");
+
+        let orthodox = Date::easter_orthodox(year).unwrap();
+        assert_eq!(orthodox.day_of_week(), DayOfWeek::Sun);
+        // Julian day number of March 1 in the Julian calendar.
+        let y = year as i64;
+        let jdn = 367 * y - (7 * (y + 5001)) / 4 + 91 + 1 + 1729777;
+        let jdn = jdn + easter_gauss(y, true) - 1;
+        assert_eq!(orthodox, Date::UNIX_EPOCH + (jdn - 2440588) as i32, "{year}");
+    }
+}
+
+#[test]
+fn imm() {
+    let d = |s: &str| Date::from_str(s).unwrap();
+    let imm_dates: Vec<_> =
+        Date::dates_between(d("2021-01-01"), d("2022-12-31")).filter(|d| d.is_imm_date()).collect();
+    assert_eq!(
+        format!("{imm_dates:?}"),
+        "[2021-03-17, 2021-06-16, 2021-09-15, 2021-12-15, 2022-03-16, 2022-06-15, 2022-09-21, 2022-12-21]"
+    );
+    assert_eq!(d("2021-03-17").next_imm_date(Inclusivity::Inclusive), Some(d("2021-03-17")));
+    assert_eq!(d("2021-03-17").next_imm_date(Inclusivity::Exclusive), Some(d("2021-06-16")));
+    assert_eq!(d("2021-03-18").next_imm_date(Inclusivity::Inclusive), Some(d("2021-06-16")));
+    assert_eq!(d("2021-01-01").next_imm_date(Inclusivity::Exclusive), Some(d("2021-03-17")));
+    assert_eq!(d("2021-12-16").next_imm_date(Inclusivity::Inclusive), Some(d("2022-03-16")));
+    assert_eq!(d("2021-03-17").prev_imm_date(Inclusivity::Inclusive), Some(d("2021-03-17")));
+    assert_eq!(d("2021-03-17").prev_imm_date(Inclusivity::Exclusive), Some(d("2020-12-16")));
+    assert_eq!(d("2021-03-16").prev_imm_date(Inclusivity::Inclusive), Some(d("2020-12-16")));
+    assert_eq!(d("2021-08-01").prev_imm_date(Inclusivity::Exclusive), Some(d("2021-06-16")));
+    // No IMM date before 0000-03-15 or after 9999-12-15.
+    assert_eq!(Date::MAX.next_imm_date(Inclusivity::Inclusive), None);
+    assert_eq!(d("9999-12-15").next_imm_date(Inclusivity::Inclusive), Some(d("9999-12-15")));
+    assert_eq!(d("9999-12-15").next_imm_date(Inclusivity::Exclusive), None);
+    assert_eq!(Date::MIN.prev_imm_date(Inclusivity::Inclusive), None);
+    assert_eq!(d("0000-03-15").prev_imm_date(Inclusivity::Inclusive), Some(d("0000-03-15")));
+    assert_eq!(d("0000-03-15").prev_imm_date(Inclusivity::Exclusive), None);
+    assert_eq!(Date::third_wednesday(2021, Month::Feb), Ok(d("2021-02-17")));
+
+    assert_eq!(d("2021-03-17").to_imm_code(), "H21");
+    assert_eq!(d("2021-03-17").to_imm_code_short(), "H1");
+    assert_eq!(d("2005-12-21").to_imm_code(), "Z05");
+    assert_eq!(d("2005-12-21").to_imm_code_short(), "Z5");
+    let reference = d("2021-04-01");
+    assert_eq!(Date::of_imm_code("H21", d("2021-01-01")), Ok(d("2021-03-17")));
+    assert_eq!(Date::of_imm_code("H21", reference), Ok(d("2121-03-19")));
+    assert_eq!(Date::of_imm_code("M21", reference), Ok(d("2021-06-16")));
+    assert_eq!(Date::of_imm_code("m1", reference), Ok(d("2021-06-16")));
+    assert_eq!(Date::of_imm_code("H1", reference), Ok(d("2031-03-19")));
+    assert_eq!(Date::of_imm_code("Z5", reference), Ok(d("2025-12-17")));
+    assert_eq!(Date::of_imm_code("F2", reference), Ok(d("2022-01-19")));
+    for code in ["", "H", "A21", "H123", "H2a", "21"] {
+        assert_eq!(Date::of_imm_code(code, reference), Err(DateError::ParseImmCodeError));
+    }
+    for month in 1..=12 {
+        let month = Month::of_u8(month).unwrap();
+        assert_eq!(Month::of_imm_code(month.imm_code()), Some(month));
+    }
+}
+
+#[test]
+fn checked() {
+    let date = Date::from_str("2021-01-16").unwrap();
+    assert_eq!(Date::MIN.to_string(), "0000-01-01");
+    assert_eq!(Date::MAX.to_string(), "9999-12-31");
+    assert_eq!(date.checked_add(15), Some(Date::from_str("2021-01-31").unwrap()));
+    assert_eq!(date.checked_sub(16), Some(Date::from_str("2020-12-31").unwrap()));
+    assert_eq!(Date::MAX.checked_add(1), None);
+    assert_eq!(Date::MIN.checked_sub(1), None);
+    assert_eq!(Date::MIN.checked_add(-1), None);
+    assert_eq!(date.checked_add(i32::MAX), None);
+    assert_eq!(date.checked_sub(i32::MIN), None);
+    assert_eq!(Date::MAX.checked_sub(Date::MAX - Date::MIN), Some(Date::MIN));
+    assert_eq!(date.saturating_add(i32::MAX), Date::MAX);
+    assert_eq!(date.saturating_add(i32::MIN), Date::MIN);
+    assert_eq!(date.saturating_sub(i32::MAX), Date::MIN);
+    assert_eq!(date.saturating_sub(i32::MIN), Date::MAX);
+    assert_eq!(date.saturating_add(1), date + 1);
+    // Day counts far out of range.
+    assert_eq!(Date::MIN.checked_add(i32::MAX - 1000), None);
+    assert_eq!(Date::MIN.checked_add(i32::MAX), None);
+    assert_eq!(Date::MAX.checked_add(i32::MIN + 1000), None);
+    assert_eq!(Date::MAX.checked_sub(i32::MAX - 1000), None);
+    assert_eq!(Date::MIN.checked_sub(i32::MIN + 1000), None);
+    assert_eq!(Date::MIN.saturating_add(i32::MAX - 1000), Date::MAX);
+    assert_eq!(Date::MAX.saturating_add(i32::MIN + 1000), Date::MIN);
+    assert_eq!(Date::MAX.saturating_sub(i32::MAX - 1000), Date::MIN);
+    assert_eq!(Date::MIN.saturating_sub(i32::MIN + 1000), Date::MAX);
+}
+
+#[test]
+fn iterators_double_ended() {
+    let lo = Date::from_str("2021-01-14").unwrap();
+    let up = Date::from_str("2021-01-19").unwrap();
+    let mut dates = Date::dates_between(lo, up);
+    assert_eq!(dates.len(), 6);
+    assert_eq!(dates.next_back(), Some(up));
+    assert_eq!(dates.next(), Some(lo));
+    assert_eq!(dates.len(), 4);
+    let dates: Vec<_> = dates.rev().collect();
+    assert_eq!(format!("{dates:?}"), "[2021-01-18, 2021-01-17, 2021-01-16, 2021-01-15]");
+    assert_eq!(Date::dates_between(up, lo).len(), 0);
+    let mut dates = Date::dates_between(lo, lo);
+    assert_eq!((dates.next_back(), dates.next(), dates.next_back()), (Some(lo), None, None));
+    let weekdays: Vec<_> = Date::weekdays_between(lo, up).rev().collect();
+    assert_eq!(format!("{weekdays:?}"), "[2021-01-19, 2021-01-18, 2021-01-15, 2021-01-14]");
+    let mut weekdays = Date::weekdays_between(lo, up);
+    assert_eq!(weekdays.next_back(), Some(up));
+    assert_eq!(weekdays.next(), Some(lo));
+    assert_eq!(weekdays.next_back(), Some(up - 1));
+    assert_eq!(weekdays.next(), Some(lo + 1));
+    assert_eq!(weekdays.next_back(), None);
+    assert_eq!(weekdays.next(), None);
+    let business_days: Vec<_> = lo.business_days_until(up, |d| d.is_weekend()).rev().collect();
+    assert_eq!(format!("{business_days:?}"), "[2021-01-17, 2021-01-16]");
+    let max = Date::dates_between(Date::MAX - 1, Date::MAX).rev().collect::<Vec<_>>();
+    assert_eq!(max, [Date::MAX, Date::MAX - 1]);
+    let min = Date::weekdays_between(Date::MIN, Date::MIN + 3).rev().count();
+    assert_eq!(min, 2);
+}
@@ -0,0 +1,113 @@
+use std::str::FromStr;
+use timens::{Date, DateError, DateTime, OfDay, ParseDateTimeError, Span, Tz};
+
+#[test]
+fn parse_and_display() {
+    let dt = DateTime::from_str("2021-08-27 00:09:46.5").unwrap();
+    assert_eq!(dt.date(), Date::from_str("2021-08-27").unwrap());
+    assert_eq!(dt.ofday(), OfDay::from_str("00:09:46.5").unwrap());
+    assert_eq!(dt.to_string(), "2021-08-27 00:09:46.5");
+    assert_eq!(format!("{dt:?}"), "2021-08-27 00:09:46.5");
+    assert_eq!(DateTime::from_str("2021-08-27T00:09:46.5"), Ok(dt));
+    assert_eq!(DateTime::from_str("2021-08-27"), Err(ParseDateTimeError::NoSeparator));
+    assert!(matches!(
+        DateTime::from_str("2021-13-27 00:00"),
+        Err(ParseDateTimeError::DateError(_))
+    ));
+    assert!(matches!(
+        DateTime::from_str("2021-08-27 25:00"),
+        Err(ParseDateTimeError::OfDayError(_))
+    ));
+    let next = DateTime::create(dt.date(), OfDay::START_OF_NEXT_DAY).unwrap();
+    assert_eq!(next.to_string(), "2021-08-28 00:00:00");
+    let (date, ofday) = dt.into();
+    assert_eq!(DateTime::try_from((date, ofday)), Ok(dt));
+    assert_eq!(
+        DateTime::create(Date::MAX, OfDay::START_OF_NEXT_DAY),
+        Err(DateError::InvalidYear(10000))
+    );
+    assert!(matches!(
+        DateTime::from_str("9999-12-31 24:00"),
+        Err(ParseDateTimeError::DateError(DateError::InvalidYear(10000)))
+    ));
+    assert!(dt < next);
+}
+
+#[test]
+fn arithmetic() {
+    let dt = DateTime::from_str("2021-08-27 22:30").unwrap();
+    assert_eq!((dt + Span::HR * 2).to_string(), "2021-08-28 00:30:00");
+    assert_eq!((dt - Span::DAY * 31).to_string(), "2021-07-27 22:30:00");
+    assert_eq!((dt - Span::HR * 23).to_string(), "2021-08-26 23:30:00");
+    let mut dt2 = dt;
+    dt2 += Span::DAY * 5 + Span::MIN;
+    assert_eq!(dt2.to_string(), "2021-09-01 22:31:00");
+    assert_eq!(dt2 - dt, Span::DAY * 5 + Span::MIN);
+    assert_eq!(dt - dt2, -(Span::DAY * 5 + Span::MIN));
+    dt2 -= Span::DAY * 5 + Span::MIN;
+    assert_eq!(dt2, dt);
+    let max = DateTime::create(Date::MAX, OfDay::from_str("23:00").unwrap()).unwrap();
+    assert_eq!(max.checked_add(Span::HR * 2), None);
+    assert_eq!(max.checked_sub(Span::HR * 2).unwrap().to_string(), "9999-12-31 21:00:00");
+    let min = DateTime::create(Date::MIN, OfDay::START_OF_DAY).unwrap();
+    assert_eq!(max.checked_diff(min), None);
+    assert_eq!(min.checked_diff(max), None);
+    assert_eq!(dt.checked_diff(dt - Span::DAY), Some(Span::DAY));
+}
+
+#[test]
+fn time_conversion() {
+    let dt = DateTime::from_str("2021-08-27 09:30").unwrap();
+    let time = dt.to_time(Tz::America__New_York).unwrap();
+    assert_eq!(time, dt.to_time_gmt() + Span::HR * 4);
+    assert_eq!(DateTime::of_time(time, Tz::America__New_York), dt);
+    assert_eq!(time.to_date_time(Tz::Asia__Hong_Kong).to_string(), "2021-08-27 21:30:00");
+    assert_eq!(DateTime::of_time_gmt(time).to_string(), "2021-08-27 13:30:00");
+    // This local time is skipped when switching to daylight saving time.
+    let skipped = DateTime::from_str("2021-03-14 02:30").unwrap();
+    assert!(skipped.to_time(Tz::America__New_York).is_err());
+    let time = skipped.to_time_lenient(Tz::America__New_York);
+    assert_eq!(time.to_date_time(Tz::America__New_York).date(), skipped.date());
+}
+
+#[cfg(feature = "binio")]
+#[test]
+fn binio_roundtrip() {
+    let dt = DateTime::from_str("2021-08-27 00:09:46.5").unwrap();
+    let mut bytes: Vec<u8> = vec![];
+    binprot::BinProtWrite::binprot_write(&dt, &mut bytes).unwrap();
+    let rt: DateTime = binprot::BinProtRead::binprot_read(&mut bytes.as_slice()).unwrap();
+    assert_eq!(dt, rt);
+    // 24:00 is normalized on read, and rejected on the last date.
+    let read = |date: &str| {
+        let mut bytes: Vec<u8> = vec![];
+        binprot::BinProtWrite::binprot_write(&Date::from_str(date).unwrap(), &mut bytes).unwrap();
+        binprot::BinProtWrite::binprot_write(&OfDay::START_OF_NEXT_DAY, &mut bytes).unwrap();
+        let dt: Result<DateTime, _> = binprot::BinProtRead::binprot_read(&mut bytes.as_slice());
+        dt
+    };
+    let next = read("2021-08-27").unwrap();
+    assert_eq!(
+        (next.date(), next.ofday()),
+        (Date::from_str("2021-08-28").unwrap(), OfDay::START_OF_DAY)
+    );
+    assert!(read("9999-12-31").is_err());
+}
+
+#[cfg(feature = "sexp")]
+#[test]
+fn sexp_roundtrip() {
+    let dt = DateTime::from_str("2021-08-27 00:09:46.5").unwrap();
+    let sexp = rsexp::SexpOf::sexp_of(&dt);
+    assert_eq!(sexp.to_string(), "(2021-08-27 00:09:46.5)");
+    let rt: DateTime = rsexp::OfSexp::of_sexp(&sexp).unwrap();
+    assert_eq!(dt, rt)
+}
+
+#[test]
+#[should_panic(expected = "out of the span range")]
+fn sub_out_of_range() {
+    let max = DateTime::create(Date::MAX, OfDay::START_OF_DAY).unwrap();
+    let min = DateTime::create(Date::MIN, OfDay::START_OF_DAY).unwrap();
+    let _ = max - min;
+}
@@ -0,0 +1,69 @@
+use std::str::FromStr;
+use timens::{Date, DayCount};
+
+fn d(s: &str) -> Date {
+    Date::from_str(s).unwrap()
+}
+
+fn assert_close(v1: f64, v2: f64) {
+    assert!((v1 - v2).abs() < 1e-5, "{v1} {v2}")
+}
+
+#[test]
+fn act() {
+    let (start, end) = (d("2021-01-15"), d("2021-07-15"));
+    assert_close(DayCount::Act360.year_fraction(start, end), 181. / 360.);
+    assert_close(DayCount::Act365Fixed.year_fraction(start, end), 181. / 365.);
+    assert_close(DayCount::Act360.year_fraction(end, start), -181. / 360.);
+    assert_close(DayCount::Act365Fixed.year_fraction(start, start), 0.);
+}
+
+// Examples from the ISDA memo "Actual/Actual (ICMA) and Actual/Actual (ISDA)".
+#[test]
+fn act_act() {
+    let check = |start: &str, end: &str, ref_start: &str, ref_end: &str, isda: f64, icma: f64| {
+        let (start, end) = (d(start), d(end));
+        assert_close(DayCount::ActActIsda.year_fraction(start, end), isda);
+        let icma_ =
+            DayCount::ActActIcma.year_fraction_with_reference(start, end, d(ref_start), d(ref_end));
+        assert_close(icma_, icma);
+    };
+    // Semi-annual payment.
+    check("2003-11-01", "2004-05-01", "2003-11-01", "2004-05-01", 0.49772, 0.5);
+    // Short first calculation period.
+    check("1999-02-01", "1999-07-01", "1998-07-01", "1999-07-01", 0.41096, 0.41096);
+    // Short final calculation period.
+    check("1999-07-30", "2000-01-30", "1999-07-30", "2000-01-30", 0.50389, 0.5);
+    check("2000-01-30", "2000-06-30", "2000-01-30", "2000-07-30", 0.41530, 0.41758);
+    assert_close(DayCount::ActActIsda.year_fraction(d("2002-08-15"), d("2003-07-15")), 0.91507);
+    assert_close(DayCount::ActActIsda.year_fraction(d("2003-07-15"), d("2004-01-15")), 0.50400);
+    assert_close(DayCount::ActActIsda.year_fraction(d("2003-01-01"), d("2006-01-01")), 3.);
+    assert_close(DayCount::ActActIcma.year_fraction(d("2003-07-15"), d("2004-01-15")), 0.5);
+}
+
+#[test]
+fn thirty_360() {
+    let check = |start: &str, end: &str, us: f64, e: f64| {
+        let (start, end) = (d(start), d(end));
+        assert_close(DayCount::Thirty360Us.year_fraction(start, end) * 360., us);
+        assert_close(DayCount::Thirty360E.year_fraction(start, end) * 360., e);
+    };
+    check("2021-01-15", "2021-07-15", 180., 180.);
+    check("2021-01-31", "2021-07-31", 180., 180.);
+    check("2021-01-30", "2021-07-31", 180., 180.);
+    check("2021-01-29", "2021-07-31", 182., 181.);
+    check("2021-02-28", "2021-08-31", 180., 182.);
+    check("2020-02-29", "2021-02-28", 360., 359.);
+    check("2021-03-31", "2021-04-30", 30., 30.);
+    check("2006-08-31", "2007-02-28", 178., 178.);
+}
+
+#[test]
+fn bus_252() {
+    let (start, end) = (d("2021-11-22"), d("2021-11-29"));
+    assert_close(DayCount::Bus252.year_fraction(start, end), 5. / 252.);
+    let is_business_day = |d: Date| d.is_weekday() && d != Date::from_str("2021-11-25").unwrap();
+    assert_close(DayCount::bus_252(start, end, is_business_day), 4. / 252.);
+    assert_close(DayCount::bus_252(end, start, is_business_day), -4. / 252.);
+    assert_close(DayCount::Bus252.year_fraction(start, start), 0.);
+}
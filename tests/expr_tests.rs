@@ -0,0 +1,85 @@
+use std::str::FromStr;
+use timens::{Date, DateOrTime, ParseExprError, Span, Time, Tz};
+
+fn date(s: &str) -> DateOrTime {
+    DateOrTime::Date(Date::from_str(s).unwrap())
+}
+
+fn time(s: &str) -> DateOrTime {
+    DateOrTime::Time(Time::from_str(s).unwrap())
+}
+
+#[test]
+fn parse() {
+    // A Saturday, at 00:30 in London and 19:30 the day before in New York.
+    let now = Time::from_str("2021-01-16 00:30:00Z").unwrap();
+    let parse = |s: &str| DateOrTime::parse(s, now, Tz::Europe__London);
+    assert_eq!(parse("now"), Ok(DateOrTime::Time(now)));
+    assert_eq!(parse("NOW"), Ok(DateOrTime::Time(now)));
+    assert_eq!(parse("today"), Ok(date("2021-01-16")));
+    assert_eq!(parse("yesterday"), Ok(date("2021-01-15")));
+    assert_eq!(parse("tomorrow"), Ok(date("2021-01-17")));
+    assert_eq!(parse("today America/New_York"), Ok(date("2021-01-15")));
+    assert_eq!(parse("2021-03-04"), Ok(date("2021-03-04")));
+    assert_eq!(parse("next friday"), Ok(date("2021-01-22")));
+    assert_eq!(parse("next Sat"), Ok(date("2021-01-23")));
+    assert_eq!(parse("last friday"), Ok(date("2021-01-15")));
+    assert_eq!(parse("last saturday"), Ok(date("2021-01-09")));
+    assert_eq!(parse("-3d"), Ok(DateOrTime::Time(now - Span::DAY * 3)));
+    assert_eq!(parse("now-15m"), Ok(DateOrTime::Time(now - Span::MIN * 15)));
+    assert_eq!(parse("now - 15m"), Ok(DateOrTime::Time(now - Span::MIN * 15)));
+    assert_eq!(parse("now +1h -15m"), Ok(DateOrTime::Time(now + Span::MIN * 45)));
+    assert_eq!(parse("today+1d"), Ok(date("2021-01-17")));
+    assert_eq!(
+        parse("yesterday -1w"),
+        Err(ParseExprError::ParseSpanError(Span::of_string_strict("-1w").unwrap_err()))
+    );
+    // Offsets require a unit.
+    assert_eq!(
+        parse("now-15"),
+        Err(ParseExprError::ParseSpanError(Span::of_string_strict("-15").unwrap_err()))
+    );
+    assert!(matches!(parse("now - 15"), Err(ParseExprError::ParseSpanError(_))));
+    // Offsets moving out of the supported range are rejected.
+    assert_eq!(parse("now+100000d"), Err(ParseExprError::OutOfRange("+100000d".to_string())));
+    assert_eq!(parse("today+100000d"), Ok(date("2294-11-01")));
+    assert_eq!(
+        parse("0100-01-01 -100000d"),
+        Err(ParseExprError::OutOfRange("-100000d".to_string()))
+    );
+    assert_eq!(
+        parse("9900-01-01 +1000000h"),
+        Err(ParseExprError::OutOfRange("+1000000h".to_string()))
+    );
+    assert_eq!(parse("9900-01-01 16:00"), Err(ParseExprError::OutOfRange("16:00".to_string())));
+    assert_eq!(parse("today+2h"), Ok(time("2021-01-16 02:00:00Z")));
+    assert_eq!(parse("today 16:00"), Ok(time("2021-01-16 16:00:00Z")));
+    assert_eq!(parse("16:00"), Ok(time("2021-01-16 16:00:00Z")));
+    assert_eq!(parse("today 16:00 America/New_York"), Ok(time("2021-01-15 21:00:00Z")));
+    assert_eq!(parse("2021-06-01 16:00"), Ok(time("2021-06-01 15:00:00Z")));
+    assert_eq!(parse("2021-06-01 16:00Z"), Ok(time("2021-06-01 16:00:00Z")));
+    assert_eq!(parse("next monday 09:30 -1d"), Ok(time("2021-01-17 09:30:00Z")));
+    assert_eq!(parse("America/New_York"), Ok(DateOrTime::Time(now)));
+    // 01:30 does not exist in London on 2021-03-28.
+    assert_eq!(parse("2021-03-28 01:30"), Ok(time("2021-03-28 01:30:00Z")));
+
+    assert_eq!(parse(""), Err(ParseExprError::EmptyString));
+    assert_eq!(parse("later"), Err(ParseExprError::UnexpectedToken("later".to_string())));
+    assert_eq!(parse("today soon"), Err(ParseExprError::UnexpectedToken("soon".to_string())));
+    assert_eq!(parse("next"), Err(ParseExprError::MissingDayOfWeek("next".to_string())));
+    assert_eq!(parse("next week"), Err(ParseExprError::MissingDayOfWeek("next week".to_string())));
+    assert_eq!(parse("now 16:00"), Err(ParseExprError::OfDayAfterTime("16:00".to_string())));
+    assert!(matches!(parse("2021-02-30"), Err(ParseExprError::DateError(_))));
+    assert!(matches!(parse("today 25:00"), Err(ParseExprError::ParseOfDayError(_))));
+    assert_eq!(parse("now -"), Err(ParseExprError::UnexpectedToken("-".to_string())));
+}
+
+#[test]
+fn to_time() {
+    let tz = Tz::America__New_York;
+    let expected = Time::from_str("2021-01-16 05:00:00Z").ok();
+    assert_eq!(date("2021-01-16").to_time(tz), expected);
+    assert_eq!(time("2021-01-16 05:00:00Z").to_time(tz), expected);
+    assert_eq!(date("9999-12-31").to_time(tz), None);
+    assert_eq!(date("0000-01-01").to_time(tz), None);
+}
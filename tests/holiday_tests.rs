@@ -0,0 +1,138 @@
+use std::str::FromStr;
+use timens::{Date, DayOfWeek, HolidayCalendar, HolidayRule, Month, Observance, RuleCalendar};
+
+fn d(s: &str) -> Date {
+    Date::from_str(s).unwrap()
+}
+
+fn nyse() -> RuleCalendar {
+    RuleCalendar::new(vec![
+        HolidayRule::Fixed { month: Month::Jan, day: 1, observance: Observance::SundayToMonday },
+        HolidayRule::NthWeekday { month: Month::Jan, n: 3, day_of_week: DayOfWeek::Mon },
+        HolidayRule::NthWeekday { month: Month::Feb, n: 3, day_of_week: DayOfWeek::Mon },
+        HolidayRule::EasterOffset(-2),
+        HolidayRule::NthWeekday { month: Month::May, n: -1, day_of_week: DayOfWeek::Mon },
+        HolidayRule::Fixed { month: Month::Jul, day: 4, observance: Observance::NearestWeekday },
+        HolidayRule::NthWeekday { month: Month::Sep, n: 1, day_of_week: DayOfWeek::Mon },
+        HolidayRule::NthWeekday { month: Month::Nov, n: 4, day_of_week: DayOfWeek::Thu },
+        HolidayRule::Fixed { month: Month::Dec, day: 25, observance: Observance::NearestWeekday },
+    ])
+}
+
+#[test]
+fn rules() {
+    let cal = nyse();
+    let holidays = cal.holidays_between(d("2021-01-01"), d("2021-12-31"));
+    assert_eq!(
+        format!("{holidays:?}"),
+        "[2021-01-01, 2021-01-18, 2021-02-15, 2021-04-02, 2021-05-31, 2021-07-05, 2021-09-06, 2021-11-25, 2021-12-24]"
+    );
+    // Jan 1 2022 is a Saturday and is not moved under the Sunday to Monday rule.
+    assert!(!cal.is_holiday(d("2021-12-31")));
+    let holidays = cal.holidays_between(d("2022-01-01"), d("2022-12-31"));
+    assert_eq!(
+        format!("{holidays:?}"),
+        "[2022-01-01, 2022-01-17, 2022-02-21, 2022-04-15, 2022-05-30, 2022-07-04, 2022-09-05, 2022-11-24, 2022-12-26]"
+    );
+    let new_year =
+        HolidayRule::Fixed { month: Month::Jan, day: 1, observance: Observance::NearestWeekday };
+    assert_eq!(new_year.date_in_year(2022), Some(d("2021-12-31")));
+    assert!(new_year.is_holiday(d("2021-12-31")));
+    let boxing_day =
+        HolidayRule::Fixed { month: Month::Dec, day: 26, observance: Observance::NextMonday };
+    assert_eq!(boxing_day.date_in_year(2020), Some(d("2020-12-28")));
+    assert_eq!(boxing_day.date_in_year(2021), Some(d("2021-12-27")));
+    let one_off = HolidayRule::OneOff(d("2022-09-19"));
+    assert_eq!(one_off.date_in_year(2022), Some(d("2022-09-19")));
+    assert_eq!(one_off.date_in_year(2021), None);
+    assert_eq!(HolidayRule::EasterOffset(1).date_in_year(2021), Some(d("2021-04-05")));
+    assert_eq!(HolidayRule::EasterOffset(39).date_in_year(2021), Some(d("2021-05-13")));
+    assert_eq!(HolidayRule::OrthodoxEasterOffset(-2).date_in_year(2021), Some(d("2021-04-30")));
+    // Offsets spanning more than a year.
+    let late = HolidayRule::EasterOffset(400);
+    assert_eq!(late.date_in_year(2021), Some(d("2022-05-09")));
+    assert!(late.is_holiday(d("2022-05-09")));
+    assert!(!late.is_holiday(d("2021-05-09")));
+    let early = HolidayRule::OrthodoxEasterOffset(-800);
+    let date = early.date_in_year(2021).unwrap();
+    assert_eq!(date.year(), 2019);
+    assert!(early.is_holiday(date));
+}
+
+#[test]
+fn range_ends() {
+    // 0000-01-01 is a Saturday, observed on the day before which does not exist.
+    let new_year =
+        HolidayRule::Fixed { month: Month::Jan, day: 1, observance: Observance::NearestWeekday };
+    assert_eq!(Observance::NearestWeekday.observed(Date::MIN), None);
+    assert_eq!(Observance::Unadjusted.observed(Date::MIN), Some(Date::MIN));
+    assert_eq!(new_year.date_in_year(0), None);
+    assert!(!new_year.is_holiday(d("0000-01-03")));
+    assert!(!new_year.is_holiday(Date::MAX));
+    assert_eq!(HolidayRule::EasterOffset(-200).date_in_year(0), None);
+    assert_eq!(HolidayRule::EasterOffset(300).date_in_year(9999), None);
+    assert!(!HolidayRule::EasterOffset(-200).is_holiday(Date::MIN));
+    assert!(!HolidayRule::OrthodoxEasterOffset(i32::MIN).is_holiday(Date::MAX));
+    let boxing_day =
+        HolidayRule::Fixed { month: Month::Dec, day: 26, observance: Observance::NextMonday };
+    assert_eq!(boxing_day.date_in_year(9999), Some(d("9999-12-27")));
+    assert!(boxing_day.is_holiday(d("9999-12-27")));
+}
+
+#[test]
+fn business_days() {
+    let cal = nyse();
+    assert!(cal.is_business_day(d("2021-11-24")));
+    assert!(!cal.is_business_day(d("2021-11-25")));
+    assert!(!cal.is_business_day(d("2021-11-27")));
+    let date = d("2021-11-24");
+    assert_eq!(
+        date.add_business_days_rounding_forward(1, |d| cal.is_business_day(d)),
+        d("2021-11-26")
+    );
+    assert_eq!(
+        date.add_business_days_rounding_forward(2, |d| cal.is_business_day(d)),
+        d("2021-11-29")
+    );
+    assert_eq!(
+        d("2021-11-25").round_backward_to_business_day(|d| cal.is_business_day(d)),
+        d("2021-11-24")
+    );
+    let days =
+        Date::business_days_between(d("2021-12-22"), d("2022-01-04"), |d| cal.is_business_day(d))
+            .collect::<Vec<_>>();
+    assert_eq!(
+        format!("{days:?}"),
+        "[2021-12-22, 2021-12-23, 2021-12-27, 2021-12-28, 2021-12-29, 2021-12-30, 2021-12-31, 2022-01-03, 2022-01-04]"
+    );
+}
+
+#[test]
+fn combine() {
+    let us = RuleCalendar::new(vec![HolidayRule::Fixed {
+        month: Month::Jul,
+        day: 4,
+        observance: Observance::NearestWeekday,
+    }]);
+    let mut uk = RuleCalendar::default();
+    uk.push(HolidayRule::NthWeekday { month: Month::Aug, n: -1, day_of_week: DayOfWeek::Mon });
+    uk.push(HolidayRule::Fixed { month: Month::Dec, day: 25, observance: Observance::NextMonday });
+    let us = us.union(RuleCalendar::new(vec![HolidayRule::Fixed {
+        month: Month::Dec,
+        day: 25,
+        observance: Observance::NearestWeekday,
+    }]));
+    let either = (&us).union(&uk);
+    let both = (&us).intersection(&uk);
+    assert!(either.is_holiday(d("2021-07-05")));
+    assert!(either.is_holiday(d("2021-08-30")));
+    assert!(!both.is_holiday(d("2021-07-05")));
+    assert!(!both.is_holiday(d("2021-08-30")));
+    assert!(both.is_holiday(d("2023-12-25")));
+    // Christmas 2021 is a Saturday, observed on Friday in the US and Monday in the UK.
+    assert!(either.is_holiday(d("2021-12-24")));
+    assert!(either.is_holiday(d("2021-12-27")));
+    assert!(!both.is_holiday(d("2021-12-24")));
+    let boxed: Vec<Box<dyn HolidayCalendar>> = vec![Box::new(us), Box::new(uk)];
+    assert!(boxed.iter().any(|cal| cal.is_holiday(d("2021-08-30"))));
+}
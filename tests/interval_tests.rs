@@ -0,0 +1,125 @@
+use std::str::FromStr;
+use timens::{Date, Interval, IntervalSet, OfDay, Span, Time};
+
+fn t(s: &str) -> Time {
+    Time::from_str(&format!("2021-08-27 {s}Z")).unwrap()
+}
+
+fn ti(lo: &str, hi: &str) -> Interval<Time> {
+    Interval::new(t(lo), t(hi)).unwrap()
+}
+
+fn oi(lo: &str, hi: &str) -> Interval<OfDay> {
+    Interval::new(OfDay::from_str(lo).unwrap(), OfDay::from_str(hi).unwrap()).unwrap()
+}
+
+#[test]
+fn interval() {
+    let session = ti("09:30", "16:00");
+    assert!(Interval::new(t("16:00"), t("09:30")).is_none());
+    assert!(session.contains(t("09:30")));
+    assert!(!session.contains(t("16:00")));
+    assert_eq!(session.span(), Span::HR * 6 + Span::MIN * 30);
+    assert!(session.contains_interval(&ti("10:00", "11:00")));
+    assert!(!session.contains_interval(&ti("10:00", "17:00")));
+    let outage = ti("15:00", "17:00");
+    assert!(session.overlaps(&outage));
+    assert_eq!(session.intersection(&outage), Some(ti("15:00", "16:00")));
+    assert_eq!(session.union(&outage), Some(ti("09:30", "17:00")));
+    assert_eq!(session.gap(&outage), None);
+    let evening = ti("18:00", "20:00");
+    assert!(!session.overlaps(&evening));
+    assert_eq!(session.intersection(&evening), None);
+    assert_eq!(session.union(&evening), None);
+    assert_eq!(session.hull(&evening), ti("09:30", "20:00"));
+    assert_eq!(evening.gap(&session), Some(ti("16:00", "18:00")));
+    // Adjacent intervals do not overlap but can be merged.
+    let after = ti("16:00", "17:00");
+    assert!(!session.overlaps(&after));
+    assert_eq!(session.union(&after), Some(ti("09:30", "17:00")));
+    assert_eq!(session.gap(&after), None);
+    assert_eq!(session.to_string(), "[2021-08-27 09:30:00Z, 2021-08-27 16:00:00Z)");
+
+    let week =
+        Interval::new(Date::from_str("2021-01-01").unwrap(), Date::from_str("2021-01-08").unwrap())
+            .unwrap();
+    assert_eq!(week.days(), 7);
+    assert!(!week.contains(Date::from_str("2021-01-08").unwrap()));
+    assert_eq!(oi("09:30", "16:00").span(), Span::HR * 6 + Span::MIN * 30);
+}
+
+#[test]
+fn interval_set() {
+    let set: IntervalSet<Time> = vec![
+        ti("12:00", "13:00"),
+        ti("09:00", "10:00"),
+        ti("09:30", "11:00"),
+        ti("11:00", "11:30"),
+        ti("14:00", "14:00"),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(set.intervals(), [ti("09:00", "11:30"), ti("12:00", "13:00")]);
+    assert_eq!(set.total_span(), Span::HR * 3 + Span::MIN * 30);
+    assert!(set.contains(t("11:00")));
+    assert!(!set.contains(t("11:30")));
+    assert!(!set.contains(t("08:00")));
+    assert!(set.contains(t("12:59")));
+
+    let mut other = IntervalSet::from(ti("10:00", "12:30"));
+    other.insert(ti("15:00", "16:00"));
+    assert_eq!(set.union(&other).intervals(), [ti("09:00", "13:00"), ti("15:00", "16:00")]);
+    assert_eq!(set.intersection(&other).intervals(), [ti("10:00", "11:30"), ti("12:00", "12:30")]);
+    assert_eq!(set.subtract(&other).intervals(), [ti("09:00", "10:00"), ti("12:30", "13:00")]);
+    assert_eq!(other.subtract(&set).intervals(), [ti("11:30", "12:00"), ti("15:00", "16:00")]);
+    let outages: IntervalSet<Time> =
+        vec![ti("09:15", "09:20"), ti("09:40", "10:30")].into_iter().collect();
+    assert_eq!(
+        set.subtract(&outages).intervals(),
+        [ti("09:00", "09:15"), ti("09:20", "09:40"), ti("10:30", "11:30"), ti("12:00", "13:00")]
+    );
+    assert_eq!(
+        set.complement(ti("08:00", "18:00")).intervals(),
+        [ti("08:00", "09:00"), ti("11:30", "12:00"), ti("13:00", "18:00")]
+    );
+    assert_eq!(set.complement(ti("09:30", "11:00")), IntervalSet::new());
+    assert!(IntervalSet::<Time>::new().is_empty());
+
+    let sessions: IntervalSet<OfDay> =
+        vec![oi("09:30", "12:00"), oi("13:00", "16:00")].into_iter().collect();
+    assert_eq!(sessions.total_span(), Span::HR * 5 + Span::MIN * 30);
+    assert_eq!(
+        sessions.complement(oi("00:00", "24:00")).intervals(),
+        [oi("00:00", "09:30"), oi("12:00", "13:00"), oi("16:00", "24:00")]
+    );
+    let d = |s: &str| Date::from_str(s).unwrap();
+    let holidays: IntervalSet<Date> = vec![
+        Interval::new(d("2021-12-24"), d("2021-12-28")).unwrap(),
+        Interval::new(d("2021-12-31"), d("2022-01-01")).unwrap(),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(holidays.total_days(), 5);
+    assert_eq!(format!("{holidays:?}"), "[[2021-12-24, 2021-12-28), [2021-12-31, 2022-01-01)]");
+}
+
+#[test]
+fn full_range_span() {
+    let full = Interval::new(Time::MIN, Time::MAX).unwrap();
+    assert_eq!(full.checked_span(), None);
+    assert_eq!(IntervalSet::from(full).checked_total_span(), None);
+    let epoch = Time::of_int_ns_since_epoch(0);
+    let before = Interval::new(Time::MIN + Span::NS, epoch).unwrap();
+    assert_eq!(before.checked_span(), Some(Span::MAX_VALUE));
+    let after = Interval::new(epoch, Time::MAX).unwrap();
+    assert_eq!(after.checked_span(), Some(Span::MAX_VALUE));
+    let halves: IntervalSet<Time> = [before, after].into_iter().collect();
+    assert_eq!(halves.checked_total_span(), None);
+    assert_eq!(IntervalSet::from(after).total_span(), Span::MAX_VALUE);
+}
+
+#[test]
+#[should_panic(expected = "span overflow")]
+fn full_range_span_panics() {
+    Interval::new(Time::MIN, Time::MAX).unwrap().span();
+}
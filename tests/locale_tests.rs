@@ -0,0 +1,72 @@
+use std::str::FromStr;
+use timens::{Date, DayOfWeek, Locale, Month, ParseNameError};
+
+#[test]
+fn english() {
+    assert_eq!(Month::Jan.to_string(), "January");
+    assert_eq!(Month::Sep.name(), "September");
+    assert_eq!(Month::Sep.short_name(), "Sep");
+    assert_eq!(DayOfWeek::Wed.to_string(), "Wednesday");
+    assert_eq!(DayOfWeek::Thu.short_name(), "Thu");
+    for m in 1..=12 {
+        let month = Month::of_u8(m).unwrap();
+        assert_eq!(Month::from_str(month.name()), Ok(month));
+        assert_eq!(Month::from_str(month.short_name()), Ok(month));
+        assert_eq!(Month::from_str(&month.name().to_uppercase()), Ok(month));
+        assert_eq!(Month::from_str(&month.short_name().to_lowercase()), Ok(month));
+    }
+    for d in 0..7 {
+        let dow = DayOfWeek::of_u8(d).unwrap();
+        assert_eq!(DayOfWeek::from_str(dow.name()), Ok(dow));
+        assert_eq!(DayOfWeek::from_str(&dow.short_name().to_uppercase()), Ok(dow));
+    }
+    assert_eq!(Month::from_str("Janu"), Err(ParseNameError::UnknownMonth("Janu".to_string())));
+    assert_eq!(
+        DayOfWeek::from_str("Frid"),
+        Err(ParseNameError::UnknownDayOfWeek("Frid".to_string()))
+    );
+}
+
+#[test]
+fn locales() {
+    assert_eq!(Locale::from_str("fr"), Ok(Locale::Fr));
+    assert_eq!(Locale::from_str("DE"), Ok(Locale::De));
+    assert_eq!(Locale::from_str("zh_TW"), Ok(Locale::Zh));
+    assert_eq!(Locale::from_str("es-MX"), Ok(Locale::Es));
+    assert_eq!(Locale::from_str("pt"), Err(ParseNameError::UnknownLocale("pt".to_string())));
+    for locale in Locale::ALL {
+        assert_eq!(Locale::from_str(&locale.to_string()), Ok(locale));
+        for m in 1..=12 {
+            let month = Month::of_u8(m).unwrap();
+            assert_eq!(locale.parse_month(locale.month_name(month)), Ok(month));
+            assert_eq!(locale.parse_month(locale.month_short_name(month)), Ok(month));
+        }
+        for d in 0..7 {
+            let dow = DayOfWeek::of_u8(d).unwrap();
+            assert_eq!(locale.parse_day_of_week(locale.day_of_week_name(dow)), Ok(dow));
+            assert_eq!(locale.parse_day_of_week(locale.day_of_week_short_name(dow)), Ok(dow));
+        }
+    }
+    assert_eq!(Locale::Fr.month_name(Month::Aug), "août");
+    assert_eq!(Locale::Fr.parse_month("AOÛT"), Ok(Month::Aug));
+    assert_eq!(Locale::Fr.parse_month("janv"), Ok(Month::Jan));
+    assert_eq!(Locale::De.parse_month("märz"), Ok(Month::Mar));
+    assert_eq!(Locale::Es.day_of_week_name(DayOfWeek::Wed), "miércoles");
+    assert_eq!(Locale::It.day_of_week_short_name(DayOfWeek::Thu), "gio");
+    assert_eq!(Locale::Ja.parse_day_of_week("金曜日"), Ok(DayOfWeek::Fri));
+    assert_eq!(Locale::Zh.parse_day_of_week("周五"), Ok(DayOfWeek::Fri));
+    assert_eq!(Locale::Zh.parse_month("十二月"), Ok(Month::Dec));
+}
+
+#[test]
+fn to_string_long() {
+    let date = Date::from_str("2021-01-16").unwrap();
+    let long = |locale| date.to_string_long(locale);
+    assert_eq!(long(Locale::En), "Saturday, January 16, 2021");
+    assert_eq!(long(Locale::Fr), "samedi 16 janvier 2021");
+    assert_eq!(long(Locale::De), "Samstag, 16. Januar 2021");
+    assert_eq!(long(Locale::Es), "sábado, 16 de enero de 2021");
+    assert_eq!(long(Locale::It), "sabato 16 gennaio 2021");
+    assert_eq!(long(Locale::Ja), "2021年1月16日土曜日");
+    assert_eq!(long(Locale::Zh), "2021年1月16日星期六");
+}
@@ -0,0 +1,36 @@
+use std::str::FromStr;
+use timens::{with_clock, FixedClock, MonoTime, MonoTimeAnchor, Span, Time};
+
+#[test]
+fn mono_time() {
+    let t1 = MonoTime::now();
+    std::thread::sleep(std::time::Duration::from_millis(2));
+    let t2 = MonoTime::now();
+    assert!(t2 - t1 >= Span::MS * 2);
+    assert!(t1.elapsed() >= Span::MS * 2);
+    let r1 = MonoTime::now_raw();
+    assert!(MonoTime::now_raw() >= r1);
+
+    let t = MonoTime::of_int_ns(1_000);
+    assert_eq!(t + Span::US, MonoTime::of_int_ns(2_000));
+    assert_eq!(t - Span::NS * 10, MonoTime::of_int_ns(990));
+    assert_eq!((t + Span::SEC) - t, Span::SEC);
+    let mut t3 = t;
+    t3 += Span::MS;
+    t3 -= Span::US;
+    assert_eq!(t3.to_int_ns(), 1_000 + 999_000);
+    assert_eq!(format!("{t3:?}"), "MonoTime(1ms)");
+}
+
+#[test]
+fn anchor() {
+    let time = Time::from_str("2021-01-16 12:00:00Z").unwrap();
+    let anchor = with_clock(FixedClock(time), MonoTimeAnchor::now);
+    assert_eq!(anchor.time(), time);
+    let mono = anchor.mono() + Span::MS * 1500;
+    assert_eq!(anchor.to_time(mono), time + Span::MS * 1500);
+    assert_eq!(anchor.to_mono(time - Span::SEC), anchor.mono() - Span::SEC);
+
+    let anchor = MonoTimeAnchor::create(MonoTime::of_int_ns(0), time);
+    assert_eq!(anchor.to_time(MonoTime::of_int_ns(5)), time + Span::NS * 5);
+}
@@ -48,3 +48,15 @@ fn sexp_roundtrip() {
     test_sexp_rt("13:37:01", "13:37:01");
     test_sexp_rt("13:37:01.0001", "13:37:01.0001");
 }
+
+#[test]
+fn checked() {
+    let ofday = OfDay::create(23, 0, 0, 0).unwrap();
+    assert_eq!(ofday.checked_add(Span::HR), Some(OfDay::START_OF_NEXT_DAY));
+    assert_eq!(ofday.checked_add(Span::HR + Span::NS), None);
+    assert_eq!(ofday.checked_sub(Span::HR * 23), Some(OfDay::START_OF_DAY));
+    assert_eq!(ofday.checked_sub(Span::DAY), None);
+    assert_eq!(ofday.checked_add(Span::MAX_VALUE), None);
+    assert_eq!(ofday.saturating_add(Span::MAX_VALUE), OfDay::START_OF_NEXT_DAY);
+    assert_eq!(ofday.saturating_sub(Span::DAY), OfDay::START_OF_DAY);
+}
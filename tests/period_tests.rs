@@ -0,0 +1,178 @@
+use std::str::FromStr;
+use timens::{Date, ParsePeriodError, Period, Span, Time, Tz};
+
+fn d(s: &str) -> Date {
+    Date::from_str(s).unwrap()
+}
+
+#[test]
+fn to_string() {
+    let test_str = |p: Period, str: &str, iso: &str| {
+        assert_eq!(p.to_string(), str);
+        assert_eq!(p.to_string_iso8601(), iso);
+        assert_eq!(Period::from_str(str), Ok(p));
+        assert_eq!(Period::from_str(iso), Ok(p));
+    };
+    test_str(Period::ZERO, "0D", "P0D");
+    test_str(Period::create(1, 6, 0, 0), "1Y6M", "P1Y6M");
+    test_str(Period::of_weeks(2), "2W", "P2W");
+    test_str(Period::create(1, 2, 0, 10), "1Y2M10D", "P1Y2M10D");
+    test_str(-Period::create(1, 2, 0, 10), "-1Y2M10D", "-P1Y2M10D");
+    test_str(Period::create(1, 0, 0, -3), "1Y-3D", "P1Y-3D");
+    assert_eq!(Period::from_str("1y6m"), Ok(Period::create(1, 6, 0, 0)));
+    assert_eq!(Period::from_str("6M1Y"), Ok(Period::create(1, 6, 0, 0)));
+    assert_eq!(Period::from_str("p3d"), Ok(Period::of_days(3)));
+    assert_eq!(Period::from_str("+3D"), Ok(Period::of_days(3)));
+    assert_eq!(Period::from_str(""), Err(ParsePeriodError::EmptyString));
+    assert_eq!(Period::from_str("P"), Err(ParsePeriodError::EmptyString));
+    assert_eq!(Period::from_str("1Y2"), Err(ParsePeriodError::MissingUnit("1Y2".to_string())));
+    assert_eq!(Period::from_str("Y"), Err(ParsePeriodError::MissingNumber("Y".to_string(), 'Y')));
+    assert_eq!(
+        Period::from_str("1Y1Y"),
+        Err(ParsePeriodError::DuplicateUnit("1Y1Y".to_string(), 'Y'))
+    );
+    assert_eq!(
+        Period::from_str("1H"),
+        Err(ParsePeriodError::UnexpectedChar("1H".to_string(), 'H'))
+    );
+    assert_eq!(Period::from_str("PT1H"), Err(ParsePeriodError::TimeComponent("PT1H".to_string())));
+    assert!(matches!(Period::from_str("99999999999D"), Err(ParsePeriodError::ParseIntError(_))));
+    // A sign for the first component right after the sign of the period.
+    let unexpected = |s: &str, c| Err(ParsePeriodError::UnexpectedChar(s.to_string(), c));
+    assert_eq!(Period::from_str("--1D"), unexpected("--1D", '-'));
+    assert_eq!(Period::from_str("-P-1D"), unexpected("-P-1D", '-'));
+    assert_eq!(Period::from_str("+-1D"), unexpected("+-1D", '-'));
+    assert_eq!(Period::from_str("-P-2147483648D"), unexpected("-P-2147483648D", '-'));
+    assert_eq!(Period::from_str("-P1Y-3D"), Ok(Period::create(-1, 0, 0, 3)));
+    assert_eq!(
+        Period::from_str("-P1Y-2147483648D"),
+        Err(ParsePeriodError::Overflow("-P1Y-2147483648D".to_string()))
+    );
+    // Components equal to `i32::MIN` can be displayed.
+    let min = Period::create(0, 0, -1, i32::MIN);
+    assert_eq!(min.to_string(), "-1W2147483648D");
+    assert_eq!(min.to_string_iso8601(), "-P1W2147483648D");
+    assert_eq!(Period::from_str("P-1W-2147483648D"), Ok(min));
+}
+
+#[test]
+fn checked_arithmetic() {
+    let max = Period::of_days(i32::MAX);
+    assert_eq!(max.checked_add(Period::of_days(1)), None);
+    assert_eq!(max.checked_add(Period::of_years(1)), Some(Period::create(1, 0, 0, i32::MAX)));
+    assert_eq!(Period::of_days(i32::MIN).checked_neg(), None);
+    assert_eq!(max.checked_neg(), Some(Period::of_days(-i32::MAX)));
+    assert_eq!(Period::of_days(-2).checked_sub(max), None);
+    assert_eq!(max.checked_mul(2), None);
+    assert_eq!(Period::of_weeks(2).checked_mul(-3), Some(Period::of_weeks(-6)));
+    assert_eq!(Period::of_years(i32::MAX).checked_total_months(), None);
+    assert_eq!(Period::create(0, 0, 1, i32::MAX).checked_total_days(), None);
+    assert_eq!(Period::create(1, 2, 3, 4).checked_total_days(), Some(25));
+}
+
+#[test]
+#[should_panic(expected = "period overflow")]
+fn neg_overflow() {
+    let _ = -Period::of_days(i32::MIN);
+}
+
+#[test]
+fn add_to_date() {
+    let date = d("2021-01-31");
+    assert_eq!(date + Period::of_months(1), d("2021-02-28"));
+    assert_eq!(date + Period::from_str("1Y1M").unwrap(), d("2022-02-28"));
+    assert_eq!(date + Period::from_str("2W").unwrap(), d("2021-02-14"));
+    assert_eq!(date + Period::from_str("1M1D").unwrap(), d("2021-03-01"));
+    assert_eq!(date - Period::from_str("1M1D").unwrap(), d("2020-12-30"));
+    assert_eq!(d("2020-02-29") + Period::of_years(1), d("2021-02-28"));
+    assert_eq!(Period::of_months(3) * 4 + Period::of_days(1), Period::create(0, 12, 0, 1));
+    assert_eq!(Period::create(1, 2, 3, 4).total_months(), 14);
+    assert_eq!(Period::create(1, 2, 3, 4).total_days(), 25);
+
+    assert_eq!(date.checked_add_period(Period::of_months(1)), Some(d("2021-02-28")));
+    assert_eq!(date.checked_add_period(Period::of_years(8000)), None);
+    assert_eq!(date.checked_add_period(Period::of_years(-2022)), None);
+    assert_eq!(date.checked_add_period(Period::of_years(-2021)), Some(d("0000-01-31")));
+    assert_eq!(Date::MAX.checked_add_period(Period::of_days(1)), None);
+    assert_eq!(Date::MAX.checked_add_period(Period::of_months(-1)), Some(d("9999-11-30")));
+    assert_eq!(Date::MIN.checked_add_period(-Period::of_weeks(1)), None);
+    assert_eq!(date.checked_add_period(Period::of_years(i32::MAX)), None);
+    assert_eq!(date.checked_add_period(Period::create(0, 0, i32::MAX, 1)), None);
+    assert_eq!(Date::MIN.checked_add_period(Period::of_days(i32::MAX - 10)), None);
+    assert_eq!(Date::MAX.checked_add_period(Period::of_days(i32::MIN + 10)), None);
+}
+
+#[test]
+#[should_panic(expected = "date out of range")]
+fn add_out_of_range() {
+    let _ = d("2021-01-01") + Period::of_years(8000);
+}
+
+#[test]
+fn diff_period() {
+    let check = |a: &str, b: &str, expected: &str| {
+        let (a, b) = (d(a), d(b));
+        let period = Date::diff_period(a, b);
+        assert_eq!(period.to_string(), expected, "{a} {b}");
+        assert_eq!(a + period, b, "{a} {b}");
+    };
+    check("2021-01-31", "2021-03-01", "1M1D");
+    check("2021-01-15", "2021-01-15", "0D");
+    check("2021-01-15", "2021-01-20", "5D");
+    check("2021-01-15", "2022-03-14", "1Y1M27D");
+    check("2021-01-15", "2022-03-15", "1Y2M");
+    check("2020-02-29", "2021-02-28", "1Y");
+    check("2020-02-28", "2021-02-27", "11M30D");
+    check("2021-03-01", "2021-01-31", "-1M1D");
+    check("2022-03-14", "2021-01-15", "-1Y1M30D");
+    for a in Date::dates_between(d("2020-01-01"), d("2020-03-31")) {
+        for b in Date::dates_between(d("2019-11-25"), d("2020-05-05")) {
+            assert_eq!(a + Date::diff_period(a, b), b, "{a} {b}");
+        }
+    }
+}
+
+#[test]
+fn add_to_time() {
+    let tz = Tz::Europe__London;
+    let time = Time::from_str("2021-03-27 01:30:00 Europe/London").unwrap();
+    // The day after is the daylight saving change, 01:30 does not exist.
+    assert_eq!(
+        time.add_period(Period::of_days(1), tz).to_string_tz(tz),
+        "2021-03-28 02:30:00+01:00"
+    );
+    let time = Time::from_str("2021-03-27 12:00:00 Europe/London").unwrap();
+    let time2 = time.add_period(Period::of_days(1), tz);
+    assert_eq!(time2.to_string_tz(tz), "2021-03-28 12:00:00+01:00");
+    assert_eq!((time2 - time).to_string(), "23h");
+    let time = Time::from_str("2021-10-30 01:30:00 Europe/London").unwrap();
+    assert_eq!(
+        time.add_period(Period::of_days(1), tz).to_string_tz(tz),
+        "2021-10-31 01:30:00+01:00"
+    );
+    let time = Time::from_str("2021-01-31 10:00:00 America/New_York").unwrap();
+    let tz = Tz::America__New_York;
+    assert_eq!(
+        time.add_period(Period::of_months(6), tz).to_string_tz(tz),
+        "2021-07-31 10:00:00-04:00"
+    );
+    assert_eq!(
+        time.add_period(-Period::of_weeks(1), tz).to_string_tz(tz),
+        "2021-01-24 10:00:00-05:00"
+    );
+    assert_eq!(time.checked_add_period(Period::of_years(300), tz), None);
+    assert_eq!(time.checked_add_period(Period::of_years(-400), tz), None);
+    assert_eq!(time.checked_add_period(Period::of_years(200), tz), Some(time + Span::DAY * 73048));
+}
+
+#[cfg(feature = "sexp")]
+#[test]
+fn sexp_roundtrip() {
+    for period in ["0D", "1Y6M", "-2W", "1Y-3D"] {
+        let p: Period = period.parse().unwrap();
+        let sexp = rsexp::SexpOf::sexp_of(&p);
+        assert_eq!(sexp, rsexp::Sexp::Atom(period.as_bytes().to_owned()));
+        let rt: Period = rsexp::OfSexp::of_sexp(&sexp).unwrap();
+        assert_eq!(p, rt)
+    }
+}
@@ -0,0 +1,111 @@
+use std::str::FromStr;
+use timens::{Date, RelativeFormatter, RelativeUnit, Span, Time, Tz};
+
+#[test]
+fn format_span() {
+    let f = RelativeFormatter::new();
+    assert_eq!(f.format_span(Span::ZERO), "just now");
+    assert_eq!(f.format_span(Span::MS * 400), "just now");
+    assert_eq!(f.format_span(Span::SEC), "in 1 second");
+    assert_eq!(f.format_span(-Span::SEC * 30), "30 seconds ago");
+    assert_eq!(f.format_span(-Span::SEC * 50), "1 minute ago");
+    assert_eq!(f.format_span(-Span::MIN * 3), "3 minutes ago");
+    assert_eq!(f.format_span(Span::MIN * 90), "in 2 hours");
+    assert_eq!(f.format_span(Span::HR * 21), "in 21 hours");
+    assert_eq!(f.format_span(Span::HR * 22), "in 1 day");
+    assert_eq!(f.format_span(Span::DAY * 2), "in 2 days");
+    assert_eq!(f.format_span(-Span::DAY * 10), "1 week ago");
+    assert_eq!(f.format_span(Span::DAY * 29), "in 1 month");
+    assert_eq!(f.format_span(Span::DAY * 200), "in 7 months");
+    assert_eq!(f.format_span(-Span::DAY * 340), "1 year ago");
+    assert_eq!(f.format_span(Span::DAY * 3650), "in 10 years");
+    assert_eq!(f.format_span(Span::MIN_VALUE), "292 years ago");
+    assert_eq!((-Span::MIN * 3).to_string_relative(), "3 minutes ago");
+
+    let f = RelativeFormatter::new()
+        .with_granularity(RelativeUnit::Minute)
+        .with_threshold(RelativeUnit::Hour, 48);
+    assert_eq!(f.granularity(), RelativeUnit::Minute);
+    assert_eq!(f.format_span(Span::SEC * 20), "just now");
+    assert_eq!(f.format_span(Span::SEC * 40), "in 1 minute");
+    assert_eq!(f.format_span(-Span::HR * 36), "36 hours ago");
+    assert_eq!(f.format_span(-Span::HR * 48), "2 days ago");
+}
+
+#[test]
+fn format_time() {
+    let now = Time::from_str("2021-01-16 12:00:00Z").unwrap();
+    let time = Time::from_str("2021-01-16 11:57:00Z").unwrap();
+    assert_eq!(time.to_string_relative(now), "3 minutes ago");
+    assert_eq!(now.to_string_relative(time), "in 3 minutes");
+    let f = RelativeFormatter::new();
+    assert_eq!(f.format_time(now + Span::DAY * 2, now), "in 2 days");
+
+    // 2021-01-16 03:00 UTC is 11:00 in Hong Kong, and 22:00 the day before in New York.
+    let time = Time::from_str("2021-01-16 03:00:00Z").unwrap();
+    assert_eq!(f.format_time_calendar(time, now, Tz::Asia__Hong_Kong), "today");
+    assert_eq!(f.format_time_calendar(time, now, Tz::America__New_York), "yesterday");
+    let now = Time::from_str("2021-01-15 23:00:00Z").unwrap();
+    assert_eq!(f.format_time_calendar(time, now, Tz::Asia__Hong_Kong), "today");
+    assert_eq!(f.format_time_calendar(time, now, Tz::America__New_York), "today");
+    assert_eq!(f.format_time_calendar(time, now, Tz::UTC), "tomorrow");
+}
+
+#[test]
+fn format_date() {
+    let f = RelativeFormatter::new();
+    // 2021-01-13 is a Wednesday.
+    let today = Date::from_str("2021-01-13").unwrap();
+    let fmt = |s: &str| f.format_date(Date::from_str(s).unwrap(), today);
+    assert_eq!(fmt("2021-01-13"), "today");
+    assert_eq!(fmt("2021-01-14"), "tomorrow");
+    assert_eq!(fmt("2021-01-12"), "yesterday");
+    assert_eq!(fmt("2021-01-08"), "last Friday");
+    assert_eq!(fmt("2021-01-15"), "next Friday");
+    assert_eq!(fmt("2021-01-19"), "next Tuesday");
+    assert_eq!(fmt("2021-01-20"), "next week");
+    assert_eq!(fmt("2021-01-24"), "next week");
+    assert_eq!(fmt("2021-01-06"), "last week");
+    assert_eq!(fmt("2021-01-04"), "last week");
+    assert_eq!(fmt("2021-01-27"), "in 2 weeks");
+    assert_eq!(fmt("2020-12-23"), "3 weeks ago");
+    assert_eq!(fmt("2021-02-26"), "next month");
+    assert_eq!(fmt("2021-02-09"), "next month");
+    assert_eq!(fmt("2020-12-01"), "last month");
+    assert_eq!(fmt("2021-05-01"), "in 4 months");
+    assert_eq!(fmt("2022-06-01"), "next year");
+    assert_eq!(fmt("2020-02-01"), "last year");
+    assert_eq!(fmt("2020-03-01"), "10 months ago");
+    assert_eq!(fmt("2020-01-01"), "last year");
+    assert_eq!(fmt("2018-01-13"), "3 years ago");
+}
+
+#[test]
+fn format_date_thresholds() {
+    let today = Date::from_str("2021-01-13").unwrap();
+    let fmt = |f: &RelativeFormatter, s: &str| f.format_date(Date::from_str(s).unwrap(), today);
+    let f = RelativeFormatter::new()
+        .with_threshold(RelativeUnit::Day, 14)
+        .with_threshold(RelativeUnit::Week, 8);
+    assert_eq!(fmt(&f, "2021-01-15"), "next Friday");
+    assert_eq!(fmt(&f, "2021-01-20"), "in 7 days");
+    assert_eq!(fmt(&f, "2020-12-31"), "13 days ago");
+    assert_eq!(fmt(&f, "2021-01-27"), "in 2 weeks");
+    assert_eq!(fmt(&f, "2021-02-09"), "in 4 weeks");
+    assert_eq!(fmt(&f, "2021-03-10"), "in 2 months");
+    let f = RelativeFormatter::new().with_threshold(RelativeUnit::Day, 2);
+    assert_eq!(fmt(&f, "2021-01-14"), "tomorrow");
+    assert_eq!(fmt(&f, "2021-01-15"), "this week");
+    assert_eq!(fmt(&f, "2021-01-18"), "next week");
+    let f = RelativeFormatter::new().with_granularity(RelativeUnit::Month);
+    assert_eq!(fmt(&f, "2021-01-13"), "this month");
+    assert_eq!(fmt(&f, "2021-01-20"), "this month");
+    assert_eq!(fmt(&f, "2020-12-31"), "last month");
+    assert_eq!(fmt(&f, "2021-06-01"), "in 5 months");
+    assert_eq!(fmt(&f, "2023-06-01"), "in 2 years");
+    let f = RelativeFormatter::new().with_granularity(RelativeUnit::Year);
+    assert_eq!(fmt(&f, "2021-12-31"), "this year");
+    // Granularities below a day are ignored for dates.
+    let f = RelativeFormatter::new().with_granularity(RelativeUnit::Hour);
+    assert_eq!(fmt(&f, "2021-01-14"), "tomorrow");
+}
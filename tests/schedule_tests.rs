@@ -0,0 +1,180 @@
+use std::str::FromStr;
+use timens::{BusinessDayConvention, Date, RollRule, Schedule, ScheduleError};
+
+fn d(s: &str) -> Date {
+    Date::from_str(s).unwrap()
+}
+
+fn is_business_day(date: Date) -> bool {
+    date.is_weekday() && date != d("2021-05-31") && date != d("2021-12-24")
+}
+
+#[test]
+fn convention() {
+    let adjust = |convention: BusinessDayConvention, date: &str| {
+        convention.adjust(d(date), is_business_day).unwrap().to_string()
+    };
+    // 2021-07-31 is a Saturday.
+    assert_eq!(adjust(BusinessDayConvention::Unadjusted, "2021-07-31"), "2021-07-31");
+    assert_eq!(adjust(BusinessDayConvention::Following, "2021-07-31"), "2021-08-02");
+    assert_eq!(adjust(BusinessDayConvention::ModifiedFollowing, "2021-07-31"), "2021-07-30");
+    assert_eq!(adjust(BusinessDayConvention::Preceding, "2021-07-31"), "2021-07-30");
+    assert_eq!(adjust(BusinessDayConvention::ModifiedPreceding, "2021-07-31"), "2021-07-30");
+    // 2021-05-01 is a Saturday.
+    assert_eq!(adjust(BusinessDayConvention::Following, "2021-05-01"), "2021-05-03");
+    assert_eq!(adjust(BusinessDayConvention::ModifiedFollowing, "2021-05-01"), "2021-05-03");
+    assert_eq!(adjust(BusinessDayConvention::Preceding, "2021-05-01"), "2021-04-30");
+    assert_eq!(adjust(BusinessDayConvention::ModifiedPreceding, "2021-05-01"), "2021-05-03");
+    // 2021-05-29 is a Saturday and 2021-05-31 a holiday.
+    assert_eq!(adjust(BusinessDayConvention::Following, "2021-05-29"), "2021-06-01");
+    assert_eq!(adjust(BusinessDayConvention::ModifiedFollowing, "2021-05-29"), "2021-05-28");
+    for convention in [
+        BusinessDayConvention::Unadjusted,
+        BusinessDayConvention::Following,
+        BusinessDayConvention::ModifiedFollowing,
+        BusinessDayConvention::Preceding,
+        BusinessDayConvention::ModifiedPreceding,
+    ] {
+        assert_eq!(adjust(convention, "2021-07-29"), "2021-07-29");
+    }
+    // No business day after `Date::MAX` or before `Date::MIN`.
+    let not_max = |date: Date| date != Date::MAX;
+    assert_eq!(BusinessDayConvention::Following.adjust(Date::MAX, not_max), None);
+    assert_eq!(
+        BusinessDayConvention::ModifiedFollowing.adjust(Date::MAX, not_max),
+        Some(d("9999-12-30"))
+    );
+    let not_min = |date: Date| date != Date::MIN;
+    assert_eq!(BusinessDayConvention::Preceding.adjust(Date::MIN, not_min), None);
+    assert_eq!(
+        BusinessDayConvention::ModifiedPreceding.adjust(Date::MIN, not_min),
+        Some(d("0000-01-02"))
+    );
+    assert_eq!(BusinessDayConvention::Following.adjust(Date::MAX, |_| false), None);
+}
+
+#[test]
+fn schedule() {
+    let schedule = Schedule::generate(
+        d("2021-01-31"),
+        d("2022-01-31"),
+        3,
+        RollRule::StartDay,
+        BusinessDayConvention::ModifiedFollowing,
+        is_business_day,
+    )
+    .unwrap();
+    assert_eq!(
+        format!("{:?}", schedule.unadjusted()),
+        "[2021-01-31, 2021-04-30, 2021-07-31, 2021-10-31, 2022-01-31]"
+    );
+    assert_eq!(
+        format!("{:?}", schedule.adjusted()),
+        "[2021-01-29, 2021-04-30, 2021-07-30, 2021-10-29, 2022-01-31]"
+    );
+
+    let schedule = Schedule::generate(
+        d("2021-02-28"),
+        d("2021-09-15"),
+        2,
+        RollRule::EndOfMonth,
+        BusinessDayConvention::Following,
+        is_business_day,
+    )
+    .unwrap();
+    assert_eq!(
+        format!("{:?}", schedule.unadjusted()),
+        "[2021-02-28, 2021-04-30, 2021-06-30, 2021-08-31, 2021-09-15]"
+    );
+    assert_eq!(
+        format!("{:?}", schedule.adjusted()),
+        "[2021-03-01, 2021-04-30, 2021-06-30, 2021-08-31, 2021-09-15]"
+    );
+
+    let schedule = Schedule::generate(
+        d("2021-03-17"),
+        d("2022-03-16"),
+        3,
+        RollRule::Imm,
+        BusinessDayConvention::Following,
+        is_business_day,
+    )
+    .unwrap();
+    assert_eq!(
+        format!("{:?}", schedule.unadjusted()),
+        "[2021-03-17, 2021-06-16, 2021-09-15, 2021-12-15, 2022-03-16]"
+    );
+
+    let schedule = Schedule::generate(
+        d("2021-01-10"),
+        d("2021-04-30"),
+        1,
+        RollRule::DayOfMonth(31),
+        BusinessDayConvention::Unadjusted,
+        is_business_day,
+    )
+    .unwrap();
+    assert_eq!(
+        format!("{:?}", schedule.unadjusted()),
+        "[2021-01-10, 2021-02-28, 2021-03-31, 2021-04-30]"
+    );
+
+    let schedule = Schedule::generate(
+        d("2021-01-10"),
+        d("2021-01-10"),
+        1,
+        RollRule::StartDay,
+        BusinessDayConvention::Following,
+        is_business_day,
+    )
+    .unwrap();
+    assert_eq!(format!("{:?}", schedule.adjusted()), "[2021-01-11]");
+
+    let err = Schedule::generate(
+        d("2021-01-10"),
+        d("2021-01-09"),
+        1,
+        RollRule::StartDay,
+        BusinessDayConvention::Following,
+        is_business_day,
+    );
+    assert_eq!(err, Err(ScheduleError::StartAfterEnd(d("2021-01-10"), d("2021-01-09"))));
+    let err = Schedule::generate(
+        d("2021-01-10"),
+        d("2021-03-09"),
+        0,
+        RollRule::StartDay,
+        BusinessDayConvention::Following,
+        is_business_day,
+    );
+    assert_eq!(err, Err(ScheduleError::NonPositivePeriod(0)));
+    let schedule = Schedule::generate(
+        d("9999-01-15"),
+        d("9999-12-31"),
+        12,
+        RollRule::StartDay,
+        BusinessDayConvention::Unadjusted,
+        is_business_day,
+    )
+    .unwrap();
+    assert_eq!(format!("{:?}", schedule.unadjusted()), "[9999-01-15, 9999-12-31]");
+    let err = Schedule::generate(
+        d("9999-01-31"),
+        d("9999-12-31"),
+        1,
+        RollRule::StartDay,
+        BusinessDayConvention::Following,
+        |date: Date| date != Date::MAX,
+    );
+    assert_eq!(err, Err(ScheduleError::DateOutOfRange(Date::MAX)));
+}
+
+#[test]
+fn roll() {
+    let date = d("9999-12-10");
+    assert_eq!(RollRule::StartDay.roll(date), Some(date));
+    assert_eq!(RollRule::EndOfMonth.roll(date), Some(Date::MAX));
+    assert_eq!(RollRule::Imm.roll(date), Some(d("9999-12-15")));
+    assert_eq!(RollRule::DayOfMonth(40).roll(date), Some(Date::MAX));
+    assert_eq!(RollRule::DayOfMonth(0).roll(date), Some(d("9999-12-01")));
+}
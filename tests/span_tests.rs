@@ -1,4 +1,4 @@
-use timens::Span;
+use timens::{ParseIso8601SpanError, ParseSpanError, RoundingMode, Span, SpanUnit};
 
 fn test_str(s: Span, str: &str) {
     assert_eq!(s.to_string(), str);
@@ -86,3 +86,207 @@ fn sexp_roundtrip() {
         assert_eq!(d, rt)
     }
 }
+
+#[test]
+fn iso8601() {
+    fn test_iso(s: Span, str: &str) {
+        assert_eq!(s.to_string_iso8601(), str);
+        assert_eq!(Span::of_string_iso8601(str), Ok(s));
+    }
+    test_iso(Span::ZERO, "PT0S");
+    test_iso(Span::HR + Span::MIN * 30, "PT1H30M");
+    test_iso(Span::DAY * 3 + Span::HR * 4, "P3DT4H");
+    test_iso(Span::DAY * 3, "P3D");
+    test_iso(Span::SEC * 90, "PT1M30S");
+    test_iso(Span::MS * 500, "PT0.5S");
+    test_iso(Span::NS, "PT0.000000001S");
+    test_iso(-(Span::DAY + Span::SEC + Span::MS * 250), "-P1DT1.25S");
+    test_iso(Span::DAY * 14, "P14D");
+
+    let parse = |s: &str| Span::of_string_iso8601(s);
+    assert_eq!(parse("P2W"), Ok(Span::DAY * 14));
+    assert_eq!(parse("PT36H"), Ok(Span::DAY + Span::HR * 12));
+    assert_eq!(parse("pt1h"), Ok(Span::HR));
+    assert_eq!(parse("PT1.5H"), Ok(Span::HR + Span::MIN * 30));
+    assert_eq!(parse("PT0,25M"), Ok(Span::SEC * 15));
+    assert_eq!(parse("P0.5D"), Ok(Span::HR * 12));
+    assert_eq!(parse("PT1.0000000019S"), Ok(Span::SEC + Span::NS));
+    assert_eq!(parse("+PT1S"), Ok(Span::SEC));
+    assert_eq!(parse(""), Err(ParseIso8601SpanError::EmptyString));
+    assert_eq!(parse("T1H"), Err(ParseIso8601SpanError::MissingP("T1H".to_string())));
+    assert_eq!(parse("P1Y"), Err(ParseIso8601SpanError::CalendarUnit("P1Y".to_string(), 'Y')));
+    assert_eq!(parse("P1M"), Err(ParseIso8601SpanError::CalendarUnit("P1M".to_string(), 'M')));
+    assert_eq!(parse("PT1D"), Err(ParseIso8601SpanError::UnexpectedChar("PT1D".to_string(), 'D')));
+    assert_eq!(parse("PT1X"), Err(ParseIso8601SpanError::UnexpectedChar("PT1X".to_string(), 'X')));
+    assert_eq!(parse("PTH"), Err(ParseIso8601SpanError::MissingNumber("PTH".to_string(), 'H')));
+    assert_eq!(parse("PT1"), Err(ParseIso8601SpanError::MissingUnit("PT1".to_string())));
+    assert_eq!(parse("P"), Err(ParseIso8601SpanError::MissingUnit("P".to_string())));
+    assert_eq!(parse("PT"), Err(ParseIso8601SpanError::MissingUnit("PT".to_string())));
+    assert_eq!(
+        parse("PT1.5H1M"),
+        Err(ParseIso8601SpanError::FractionNotLast("PT1.5H1M".to_string()))
+    );
+    assert_eq!(parse("P1W2D"), Ok(Span::DAY * 9));
+    let out_of_order = |s: &str, c| Err(ParseIso8601SpanError::UnitOutOfOrder(s.to_string(), c));
+    assert_eq!(parse("PT1H1H"), out_of_order("PT1H1H", 'H'));
+    assert_eq!(parse("P1D1D"), out_of_order("P1D1D", 'D'));
+    assert_eq!(parse("PT1S1H"), out_of_order("PT1S1H", 'H'));
+    assert_eq!(parse("PT1M2M"), out_of_order("PT1M2M", 'M'));
+    assert_eq!(parse("P1D2W"), out_of_order("P1D2W", 'W'));
+    assert_eq!(parse("PTT1H"), Err(ParseIso8601SpanError::RepeatedT("PTT1H".to_string())));
+    assert_eq!(parse("PT1HT1M"), Err(ParseIso8601SpanError::RepeatedT("PT1HT1M".to_string())));
+    assert_eq!(parse("P1DT"), Err(ParseIso8601SpanError::DanglingT("P1DT".to_string())));
+    assert_eq!(parse("P999999D"), Err(ParseIso8601SpanError::Overflow("P999999D".to_string())));
+    assert_eq!(
+        parse("PT99999999999999999999S"),
+        Err(ParseIso8601SpanError::Overflow("PT99999999999999999999S".to_string()))
+    );
+}
+
+#[test]
+fn checked() {
+    assert_eq!(Span::SEC.checked_add(Span::SEC), Some(Span::SEC * 2));
+    assert_eq!(Span::MAX_VALUE.checked_add(Span::NS), None);
+    assert_eq!(Span::MIN_VALUE.checked_sub(Span::NS), None);
+    assert_eq!(Span::MIN_VALUE, -Span::MAX_VALUE);
+    assert_eq!(Span::DAY.checked_mul(1000), Some(Span::of_int_day(1000)));
+    assert_eq!(Span::DAY.checked_mul(1_000_000), None);
+    assert_eq!(Span::DAY.checked_div(0), None);
+    assert_eq!(Span::DAY.checked_div(24), Some(Span::HR));
+    assert_eq!(Span::MAX_VALUE.saturating_add(Span::DAY), Span::MAX_VALUE);
+    assert_eq!(Span::MIN_VALUE.saturating_sub(Span::DAY), Span::MIN_VALUE);
+    assert_eq!(Span::DAY.saturating_mul(-1_000_000), Span::MIN_VALUE);
+    assert_eq!(Span::HR.saturating_mul(3), Span::HR * 3);
+}
+
+#[test]
+fn of_string_strict() {
+    let strict = |s: &str| Span::of_string_strict(s);
+    assert_eq!(strict("1d2h3m4s"), Ok(Span::DAY + Span::HR * 2 + Span::MIN * 3 + Span::SEC * 4));
+    assert_eq!(strict("-1.5h"), Ok(-(Span::HR + Span::MIN * 30)));
+    assert_eq!(strict("+12m123.456ms"), Ok(Span::MIN * 12 + Span::US * 123456));
+    assert_eq!(strict("1s5ms7us9ns"), Ok(Span::SEC + Span::MS * 5 + Span::US * 7 + Span::NS * 9));
+    assert_eq!(strict("1.000000001s"), Ok(Span::SEC + Span::NS));
+    assert_eq!(strict("1.5000ns"), Err(ParseSpanError::SubNanosecond("1.5000ns".to_string(), 0)));
+    assert_eq!(strict("2ns"), Ok(Span::NS * 2));
+    for span in [Span::DAY * 12 + Span::NS, -Span::DAY * 12 + Span::NS, Span::MS * 1.234567] {
+        assert_eq!(strict(&span.to_string()), Ok(span));
+    }
+
+    let err = |s: &str| s.to_string();
+    assert_eq!(strict(""), Err(ParseSpanError::EmptyString));
+    assert_eq!(strict("-"), Err(ParseSpanError::MissingNumber(err("-"), 1)));
+    assert_eq!(strict("5"), Err(ParseSpanError::MissingUnit(err("5"), 1)));
+    assert_eq!(strict("1d5"), Err(ParseSpanError::MissingUnit(err("1d5"), 3)));
+    assert_eq!(strict("1dh"), Err(ParseSpanError::MissingNumber(err("1dh"), 2)));
+    assert_eq!(strict(".s"), Err(ParseSpanError::MissingNumber(err(".s"), 0)));
+    assert_eq!(strict("1d1d"), Err(ParseSpanError::DuplicateUnit(err("1d1d"), 3)));
+    assert_eq!(strict("1s1m"), Err(ParseSpanError::UnitOutOfOrder(err("1s1m"), 3)));
+    assert_eq!(strict("1ms1s"), Err(ParseSpanError::UnitOutOfOrder(err("1ms1s"), 4)));
+    assert_eq!(strict("1.2.3s"), Err(ParseSpanError::MultipleDots(err("1.2.3s"), 3)));
+    assert_eq!(strict("1.5ns"), Err(ParseSpanError::SubNanosecond(err("1.5ns"), 0)));
+    assert_eq!(strict("1s0.1ns"), Err(ParseSpanError::SubNanosecond(err("1s0.1ns"), 2)));
+    assert_eq!(strict("1 s"), Err(ParseSpanError::InvalidChar(err("1 s"), 1, ' ')));
+    assert_eq!(strict("1x"), Err(ParseSpanError::InvalidChar(err("1x"), 1, 'x')));
+    assert_eq!(strict("1ux"), Err(ParseSpanError::InvalidChar(err("1ux"), 2, 'x')));
+    assert_eq!(strict("1n"), Err(ParseSpanError::InvalidChar(err("1n"), 1, 'n')));
+    assert_eq!(strict("99999999999d"), Err(ParseSpanError::Overflow(err("99999999999d"))));
+    assert_eq!(
+        strict("99999999999999999999ns"),
+        Err(ParseSpanError::Overflow(err("99999999999999999999ns")))
+    );
+}
+
+#[test]
+fn lenient_overflow() {
+    let parse = |s: &str| s.parse::<Span>();
+    assert_eq!(parse("99999999999d"), Err(ParseSpanError::Overflow("99999999999d".to_string())));
+    assert_eq!(parse("100000d1d"), Ok(Span::DAY * 100001));
+    assert_eq!(
+        parse("106751d106751d"),
+        Err(ParseSpanError::Overflow("106751d106751d".to_string()))
+    );
+    assert_eq!(parse("1.0000000000000000000000001s"), Ok(Span::SEC));
+    // Unit-less input and repeated units are accepted as in the OCaml implementation.
+    assert_eq!(parse("5"), Ok(Span::ZERO));
+    assert_eq!(parse("1d1d"), Ok(Span::DAY * 2));
+}
+
+#[test]
+fn to_string_in_unit() {
+    let span = Span::HR + Span::MIN * 31 + Span::SEC * 12;
+    assert_eq!(span.to_string_in_unit(SpanUnit::Hr, 2), "1.52h");
+    assert_eq!(span.to_string_in_unit(SpanUnit::Hr, 0), "2h");
+    assert_eq!(span.to_string_in_unit(SpanUnit::Min, 1), "91.2m");
+    assert_eq!((-span).to_string_in_unit(SpanUnit::Sec, 3), "-5472.000s");
+    assert_eq!((-Span::NS).to_string_in_unit(SpanUnit::Sec, 3), "0.000s");
+    assert_eq!((Span::US * 912_300).to_string_in_unit(SpanUnit::Ms, 1), "912.3ms");
+    assert_eq!(Span::NS.to_string_in_unit(SpanUnit::Day, 30), "0.000000000000011574d");
+    assert_eq!(Span::MAX_VALUE.to_string_in_unit(SpanUnit::Ns, 2), "9223372036854775807.00ns");
+
+    let sig = |span: Span, unit, digits| span.to_string_in_unit_significant(unit, digits);
+    assert_eq!(sig(span, SpanUnit::Hr, 3), "1.52h");
+    assert_eq!(sig(span, SpanUnit::Sec, 2), "5472s");
+    assert_eq!(sig(Span::MS * 1.2345, SpanUnit::Sec, 2), "0.0012s");
+    assert_eq!(sig(Span::MS * 9.96, SpanUnit::Ms, 2), "10ms");
+    assert_eq!(sig(Span::MS * 0.996, SpanUnit::Ms, 2), "1.0ms");
+    assert_eq!(sig(Span::ZERO, SpanUnit::Ms, 3), "0.00ms");
+    assert_eq!(sig(-Span::US * 912_345, SpanUnit::Ms, 4), "-912.3ms");
+    assert_eq!(sig(Span::ZERO, SpanUnit::Ms, 0), "0ms");
+    assert_eq!(sig(Span::SEC, SpanUnit::Sec, 40), "1.000000000000000000s");
+    assert_eq!(
+        sig(Span::MAX_VALUE, SpanUnit::Ns, u32::MAX),
+        "9223372036854775807.000000000000000000ns"
+    );
+    assert_eq!(sig(Span::NS, SpanUnit::Day, u32::MAX), "0.000000000000011574d");
+}
+
+#[test]
+fn to_string_hum() {
+    assert_eq!(Span::ZERO.to_string_hum(), "0s");
+    assert_eq!(Span::NS.to_string_hum(), "1ns");
+    assert_eq!((Span::HR + Span::MIN * 31 + Span::SEC * 12).to_string_hum(), "1.52h");
+    assert_eq!((Span::US * 912_300).to_string_hum(), "912.3ms");
+    assert_eq!((Span::MS * 1.23456).to_string_hum(), "1.235ms");
+    assert_eq!((-Span::DAY * 3).to_string_hum(), "-3d");
+    assert_eq!((Span::SEC * 90).to_string_hum(), "1.5m");
+    // Values rounding up to the next unit.
+    assert_eq!((Span::MIN - Span::US * 100).to_string_hum(), "1m");
+    assert_eq!((-(Span::MIN - Span::US * 100)).to_string_hum(), "-1m");
+    assert_eq!((Span::DAY - Span::US * 100).to_string_hum(), "1d");
+    assert_eq!((Span::SEC - Span::NS * 100).to_string_hum(), "1s");
+    assert_eq!((Span::HR - Span::MS * 20).to_string_hum(), "1h");
+    assert_eq!((Span::MIN - Span::MS).to_string_hum(), "59.999s");
+    assert_eq!(Span::MAX_VALUE.to_string_hum(), "106751.991d");
+}
+
+#[test]
+fn round_to_unit() {
+    let span = Span::MIN * 7 + Span::SEC * 30;
+    assert_eq!(span.round_nearest(Span::MIN), Span::MIN * 8);
+    assert_eq!(span.round_down(Span::MIN), Span::MIN * 7);
+    assert_eq!(span.round_up(Span::MIN), Span::MIN * 8);
+    assert_eq!((span - Span::NS).round_nearest(Span::MIN), Span::MIN * 7);
+    assert_eq!((-span).round_nearest(Span::MIN), -Span::MIN * 7);
+    assert_eq!((-span).round_down(Span::MIN), -Span::MIN * 8);
+    assert_eq!((-span).round_up(Span::MIN), -Span::MIN * 7);
+    assert_eq!((Span::MIN * 7).round_up(Span::MIN), Span::MIN * 7);
+    assert_eq!(span.round(Span::SEC * 45, RoundingMode::Nearest), Span::SEC * 450);
+    assert_eq!(span.round(Span::SEC * 45, RoundingMode::Down), Span::SEC * 450);
+    assert_eq!(span.round(Span::SEC * 45, RoundingMode::Up), Span::SEC * 450);
+    assert_eq!(span.round(Span::SEC * 100, RoundingMode::Nearest), Span::SEC * 500);
+    assert_eq!(span.round(Span::SEC * 100, RoundingMode::Down), Span::SEC * 400);
+    assert_eq!(span.round(Span::SEC * 100, RoundingMode::Up), Span::SEC * 500);
+    // Multiples out of range saturate.
+    let max_days = Span::DAY * 106_751;
+    assert_eq!(Span::MAX_VALUE.round_up(Span::DAY), Span::MAX_VALUE);
+    assert_eq!(Span::MAX_VALUE.round_nearest(Span::DAY), Span::MAX_VALUE);
+    assert_eq!(Span::MAX_VALUE.round_down(Span::DAY), max_days);
+    assert_eq!(Span::MIN_VALUE.round_down(Span::DAY), Span::MIN_VALUE);
+    assert_eq!(Span::MIN_VALUE.round_up(Span::DAY), -max_days);
+    assert_eq!(Span::MAX_VALUE.round_up(-Span::DAY), Span::MAX_VALUE);
+    assert_eq!(Span::MAX_VALUE.checked_next_multiple(Span::DAY), None);
+    assert_eq!(max_days.checked_next_multiple(Span::DAY), Some(max_days));
+    assert_eq!((max_days - Span::NS).checked_next_multiple(Span::DAY), Some(max_days));
+    assert_eq!(span.checked_next_multiple(-Span::MIN), Some(Span::MIN * 8));
+}
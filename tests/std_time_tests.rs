@@ -0,0 +1,55 @@
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use timens::{Span, StdTimeError, Time};
+
+#[test]
+fn span_duration() {
+    let span = Span::SEC * 3 + Span::NS * 7;
+    assert_eq!(Duration::try_from(span), Ok(Duration::new(3, 7)));
+    assert_eq!(Span::try_from(Duration::new(3, 7)), Ok(span));
+    assert_eq!(Duration::try_from(Span::ZERO), Ok(Duration::ZERO));
+    assert_eq!(Duration::try_from(-span), Err(StdTimeError::NegativeSpan(-span)));
+    assert_eq!(Duration::try_from(Span::MAX_VALUE), Ok(Duration::from_nanos(i64::MAX as u64)));
+    assert_eq!(Span::try_from(Duration::from_nanos(i64::MAX as u64)), Ok(Span::MAX_VALUE));
+    assert_eq!(
+        Span::try_from(Duration::from_nanos(i64::MAX as u64 + 1)),
+        Err(StdTimeError::OutOfRange)
+    );
+    assert_eq!(Span::try_from(Duration::MAX), Err(StdTimeError::OutOfRange));
+    assert_eq!((-span).unsigned_abs_duration(), Duration::new(3, 7));
+    assert_eq!((-span).to_duration_saturating(), Duration::ZERO);
+    assert_eq!(span.to_duration_saturating(), Duration::new(3, 7));
+}
+
+#[test]
+fn time_system_time() {
+    let time = Time::from_str("2021-01-16 12:34:56.789Z").unwrap();
+    let system_time = SystemTime::from(time);
+    assert_eq!(
+        system_time.duration_since(UNIX_EPOCH).unwrap(),
+        Duration::new(1610800496, 789_000_000)
+    );
+    assert_eq!(Time::try_from(system_time), Ok(time));
+
+    let time = Time::from_str("1960-01-01 00:00:00.5Z").unwrap();
+    let system_time = SystemTime::from(time);
+    assert_eq!(
+        UNIX_EPOCH.duration_since(system_time).unwrap(),
+        Duration::new(315619199, 500_000_000)
+    );
+    assert_eq!(Time::try_from(system_time), Ok(time));
+
+    assert_eq!(Time::try_from(SystemTime::from(Time::MAX)), Ok(Time::MAX));
+    let min = Time::MIN + Span::NS;
+    assert_eq!(Time::try_from(SystemTime::from(min)), Ok(min));
+    let too_late = SystemTime::from(Time::MAX) + Duration::from_nanos(1);
+    assert_eq!(Time::try_from(too_late), Err(StdTimeError::OutOfRange));
+    // `Time::MIN` is 2^63ns before the epoch, which does not fit in a `Span`.
+    assert_eq!(
+        UNIX_EPOCH.duration_since(SystemTime::from(Time::MIN)).unwrap(),
+        Duration::from_nanos(1 << 63)
+    );
+    assert_eq!(Time::try_from(SystemTime::from(Time::MIN)), Err(StdTimeError::OutOfRange));
+    let too_early = SystemTime::from(min) - Duration::from_nanos(2);
+    assert_eq!(Time::try_from(too_early), Err(StdTimeError::OutOfRange));
+}
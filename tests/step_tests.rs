@@ -0,0 +1,97 @@
+use std::str::FromStr;
+use timens::{OfDay, Span, Time};
+
+#[test]
+fn times_between() {
+    let lo = Time::from_str("2021-08-27 09:30:00Z").unwrap();
+    let up = Time::from_str("2021-08-27 10:00:00Z").unwrap();
+    let times = Time::times_between(lo, up, Span::MIN * 10);
+    assert_eq!(times.size_hint(), (4, Some(4)));
+    let times: Vec<_> = times.map(|t| t.to_string()).collect();
+    assert_eq!(
+        times,
+        [
+            "2021-08-27 09:30:00Z",
+            "2021-08-27 09:40:00Z",
+            "2021-08-27 09:50:00Z",
+            "2021-08-27 10:00:00Z"
+        ]
+    );
+    let mut times = lo.times_until(up - Span::SEC, Span::MIN * 10);
+    assert_eq!(times.next_back(), Some(lo + Span::MIN * 20));
+    assert_eq!(times.next(), Some(lo));
+    assert_eq!(times.size_hint(), (1, Some(1)));
+    assert_eq!(times.next_back(), Some(lo + Span::MIN * 10));
+    assert_eq!((times.next(), times.next_back()), (None, None));
+    assert_eq!(Time::times_between(up, lo, Span::MIN).count(), 0);
+    assert_eq!(Time::times_between(lo, lo, Span::MIN).count(), 1);
+    // No overflow close to the bounds of the representable range.
+    assert_eq!(Time::times_between(Time::MAX - Span::SEC, Time::MAX, Span::MIN).count(), 1);
+    assert_eq!(Time::times_between(Time::MIN, Time::MAX, Span::MAX_VALUE).count(), 3);
+    // There are 2^64 nanoseconds in the whole range, more than `usize::MAX`.
+    let mut all = Time::times_between(Time::MIN, Time::MAX, Span::NS);
+    assert_eq!(all.size_hint(), (usize::MAX, None));
+    assert_eq!(all.next(), Some(Time::MIN));
+    assert_eq!(all.next_back(), Some(Time::MAX));
+    #[cfg(target_pointer_width = "64")]
+    assert_eq!(all.size_hint(), (usize::MAX - 1, Some(usize::MAX - 1)));
+}
+
+#[test]
+fn aligned_times_between() {
+    let lo = Time::from_str("2021-08-27 09:31:12Z").unwrap();
+    let up = Time::from_str("2021-08-27 09:50:00Z").unwrap();
+    let times: Vec<_> =
+        Time::aligned_times_between(lo, up, Span::MIN * 5).map(|t| t.to_string()).collect();
+    assert_eq!(
+        times,
+        [
+            "2021-08-27 09:35:00Z",
+            "2021-08-27 09:40:00Z",
+            "2021-08-27 09:45:00Z",
+            "2021-08-27 09:50:00Z"
+        ]
+    );
+    // No multiple of the step after the lower bound fits in the range.
+    let max = Time::aligned_times_between(Time::MAX - Span::NS, Time::MAX, Span::DAY);
+    assert_eq!(max.count(), 0);
+    let min = Time::aligned_times_between(Time::MIN, Time::MIN + Span::DAY, Span::DAY);
+    assert_eq!(min.count(), 1);
+    let mut last = Time::aligned_times_between(Time::MAX - Span::DAY, Time::MAX, Span::SEC);
+    assert_eq!(last.next_back(), Some(Time::MAX.prev_multiple(Span::SEC)));
+}
+
+#[test]
+fn ofdays_between() {
+    let open = OfDay::from_str("09:30").unwrap();
+    let close = OfDay::from_str("16:00").unwrap();
+    let ofdays = OfDay::ofdays_between(open, close, Span::MIN * 5);
+    assert_eq!(ofdays.size_hint(), (79, Some(79)));
+    assert_eq!(ofdays.clone().next_back(), Some(close));
+    let ofdays: Vec<_> = open.ofdays_until(close, Span::HR * 2).rev().collect();
+    assert_eq!(format!("{ofdays:?}"), "[15:30:00, 13:30:00, 11:30:00, 09:30:00]");
+    let ofdays: Vec<_> = OfDay::aligned_ofdays_between(
+        OfDay::from_str("09:31").unwrap(),
+        OfDay::from_str("10:15").unwrap(),
+        Span::MIN * 15,
+    )
+    .collect();
+    assert_eq!(format!("{ofdays:?}"), "[09:45:00, 10:00:00, 10:15:00]");
+    let ofdays: Vec<_> = OfDay::aligned_ofdays_between(
+        OfDay::START_OF_NEXT_DAY,
+        OfDay::START_OF_NEXT_DAY,
+        Span::DAY,
+    )
+    .collect();
+    assert_eq!(ofdays, [OfDay::START_OF_NEXT_DAY]);
+    let ofdays: Vec<_> =
+        OfDay::ofdays_between(OfDay::START_OF_DAY, OfDay::START_OF_NEXT_DAY, Span::HR * 8)
+            .collect();
+    assert_eq!(format!("{ofdays:?}"), "[00:00:00, 08:00:00, 16:00:00, 24:00:00]");
+}
+
+#[test]
+#[should_panic]
+fn zero_step() {
+    let _ = OfDay::ofdays_between(OfDay::START_OF_DAY, OfDay::START_OF_NEXT_DAY, Span::ZERO);
+}
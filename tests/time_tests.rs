@@ -73,3 +73,20 @@ fn sexp_roundtrip() {
     let rt: Time = rsexp::OfSexp::of_sexp(&sexp).unwrap();
     assert_eq!(d, rt)
 }
+
+#[test]
+fn checked() {
+    let time = Time::of_date_ofday_gmt(Date::from_str("2021-01-16").unwrap(), OfDay::START_OF_DAY);
+    assert_eq!(time.checked_add(Span::DAY), Some(time + Span::DAY));
+    assert_eq!(time.checked_sub(Span::DAY), Some(time - Span::DAY));
+    assert_eq!(Time::MAX.checked_add(Span::NS), None);
+    assert_eq!(Time::MIN.checked_sub(Span::NS), None);
+    assert_eq!(time.checked_add(Span::MAX_VALUE), None);
+    assert_eq!(Time::MAX.checked_diff(Time::MIN), None);
+    assert_eq!(time.checked_diff(Time::EPOCH), Some(time - Time::EPOCH));
+    assert_eq!(time.saturating_add(Span::MAX_VALUE), Time::MAX);
+    assert_eq!(time.saturating_sub(Span::MIN_VALUE), Time::MAX);
+    assert_eq!(Time::MIN.saturating_sub(Span::DAY), Time::MIN);
+    assert_eq!(Time::MIN.to_string(), "1677-09-21 00:12:43.145224192Z");
+    assert_eq!(Time::MAX.to_string(), "2262-04-11 23:47:16.854775807Z");
+}
@@ -0,0 +1,134 @@
+use std::str::FromStr;
+use timens::{CalendarUnit, Date, OfDay, RoundingMode, Span, Time, Tz, Zone};
+
+fn t(s: &str) -> Time {
+    Time::from_str(s).unwrap()
+}
+
+#[test]
+fn start_of_day() {
+    let time = t("2021-08-27 18:30:00Z");
+    assert_eq!(time.start_of_day(Tz::Asia__Hong_Kong), t("2021-08-27 16:00:00Z"));
+    assert_eq!(time.start_of_next_day(Tz::Asia__Hong_Kong), t("2021-08-28 16:00:00Z"));
+    assert_eq!(time.start_of_day(Tz::America__New_York), t("2021-08-27 04:00:00Z"));
+    assert_eq!(time.start_of_day(Zone::UTC), t("2021-08-27 00:00:00Z"));
+    let kolkata = Zone::fixed(19800).unwrap();
+    assert_eq!(time.start_of_day(kolkata), t("2021-08-27 18:30:00Z"));
+    assert_eq!(time.start_of_next_day(kolkata), t("2021-08-28 18:30:00Z"));
+    // The day starting in London on 2021-03-28 only lasts 23 hours.
+    let time = t("2021-03-28 12:00:00Z");
+    assert_eq!(time.start_of_day(Tz::Europe__London), t("2021-03-28 00:00:00Z"));
+    assert_eq!(time.start_of_next_day(Tz::Europe__London), t("2021-03-28 23:00:00Z"));
+}
+
+#[test]
+fn day_length() {
+    let d = |s: &str| Date::from_str(s).unwrap();
+    assert_eq!(d("2021-03-28").day_length(Tz::Europe__London), Span::HR * 23);
+    assert_eq!(d("2021-10-31").day_length(Tz::Europe__London), Span::HR * 25);
+    assert_eq!(d("2021-10-30").day_length(Tz::Europe__London), Span::HR * 24);
+    assert_eq!(d("2021-03-14").day_length(Tz::America__New_York), Span::HR * 23);
+    assert_eq!(d("2021-03-14").day_length(Tz::Asia__Hong_Kong), Span::HR * 24);
+    assert_eq!(d("2021-03-28").day_length(Zone::fixed(19800).unwrap()), Span::HR * 24);
+}
+
+#[test]
+fn truncate() {
+    let kolkata = Zone::fixed(19800).unwrap();
+    let time = t("2021-08-27 10:12:34.5Z");
+    assert_eq!(time.truncate(CalendarUnit::Minute, kolkata), t("2021-08-27 10:12:00Z"));
+    assert_eq!(time.truncate(CalendarUnit::Hour, kolkata), t("2021-08-27 09:30:00Z"));
+    assert_eq!(time.truncate(CalendarUnit::Hour, Tz::Asia__Hong_Kong), t("2021-08-27 10:00:00Z"));
+    // 2021-08-27 is a Friday.
+    assert_eq!(time.truncate(CalendarUnit::Week, Tz::Europe__London), t("2021-08-22 23:00:00Z"));
+    assert_eq!(time.truncate(CalendarUnit::Month, Tz::Europe__London), t("2021-07-31 23:00:00Z"));
+    assert_eq!(time.truncate(CalendarUnit::Year, Tz::Europe__London), t("2021-01-01 00:00:00Z"));
+    assert_eq!(time.truncate(CalendarUnit::Year, Tz::Asia__Hong_Kong), t("2020-12-31 16:00:00Z"));
+    // Both 01:30 happen in London on 2021-10-31, each is truncated to its own 01:00.
+    let london = Zone::Tz(Tz::Europe__London);
+    let first = t("2021-10-31 00:30:00Z");
+    let second = t("2021-10-31 01:30:00Z");
+    assert_eq!(first.truncate(CalendarUnit::Hour, london), t("2021-10-31 00:00:00Z"));
+    assert_eq!(second.truncate(CalendarUnit::Hour, london), t("2021-10-31 01:00:00Z"));
+    assert_eq!(second.truncate(CalendarUnit::Day, london), t("2021-10-30 23:00:00Z"));
+    let time = t("2021-08-27 10:00:00Z");
+    assert_eq!(time.truncate(CalendarUnit::Hour, Tz::Asia__Hong_Kong), time);
+}
+
+#[test]
+fn round_to() {
+    let kolkata = Zone::fixed(19800).unwrap();
+    let time = t("2021-08-27 10:12:34.5Z");
+    let round = |unit, mode| time.round_to(unit, mode, kolkata);
+    assert_eq!(round(CalendarUnit::Hour, RoundingMode::Down), t("2021-08-27 09:30:00Z"));
+    assert_eq!(round(CalendarUnit::Hour, RoundingMode::Up), t("2021-08-27 10:30:00Z"));
+    assert_eq!(round(CalendarUnit::Hour, RoundingMode::Nearest), t("2021-08-27 10:30:00Z"));
+    assert_eq!(round(CalendarUnit::Minute, RoundingMode::Nearest), t("2021-08-27 10:13:00Z"));
+    assert_eq!(round(CalendarUnit::Day, RoundingMode::Nearest), t("2021-08-27 18:30:00Z"));
+    assert_eq!(round(CalendarUnit::Month, RoundingMode::Up), t("2021-08-31 18:30:00Z"));
+    assert_eq!(round(CalendarUnit::Year, RoundingMode::Nearest), t("2021-12-31 18:30:00Z"));
+    let tie = t("2021-08-27 10:00:30Z");
+    assert_eq!(
+        tie.round_to(CalendarUnit::Minute, RoundingMode::Nearest, Zone::UTC),
+        t("2021-08-27 10:01:00Z")
+    );
+    let exact = t("2021-08-27 10:00:00Z");
+    assert_eq!(exact.round_to(CalendarUnit::Hour, RoundingMode::Up, Zone::UTC), exact);
+    // The day lasts 25 hours in London, local noon is 13 hours after its start.
+    let london = Tz::Europe__London;
+    let noon = t("2021-10-31 12:00:00Z");
+    assert_eq!(
+        noon.round_to(CalendarUnit::Day, RoundingMode::Nearest, london),
+        t("2021-11-01 00:00:00Z")
+    );
+    assert_eq!(
+        (noon - Span::HR).round_to(CalendarUnit::Day, RoundingMode::Nearest, london),
+        t("2021-10-30 23:00:00Z")
+    );
+    assert_eq!(
+        noon.round_to(CalendarUnit::Day, RoundingMode::Up, london),
+        t("2021-11-01 00:00:00Z")
+    );
+}
+
+#[test]
+fn checked_near_bounds() {
+    let gmt = Tz::GMT;
+    let near_max = Time::MAX - Span::HR;
+    assert_eq!(near_max.checked_truncate(CalendarUnit::Year, gmt), Some(t("2262-01-01 00:00:00Z")));
+    assert_eq!(near_max.truncate(CalendarUnit::Month, gmt), t("2262-04-01 00:00:00Z"));
+    assert_eq!(
+        Time::MAX.checked_truncate(CalendarUnit::Minute, gmt),
+        Some(t("2262-04-11 23:47:00Z"))
+    );
+    assert_eq!(Time::MAX.checked_round_to(CalendarUnit::Minute, RoundingMode::Up, gmt), None);
+    assert_eq!(Time::MAX.checked_round_to(CalendarUnit::Year, RoundingMode::Up, gmt), None);
+    assert_eq!(Time::MAX.checked_round_to(CalendarUnit::Year, RoundingMode::Nearest, gmt), None);
+    assert_eq!(
+        Time::MAX.checked_round_to(CalendarUnit::Year, RoundingMode::Down, gmt),
+        Some(t("2262-01-01 00:00:00Z"))
+    );
+    assert_eq!(near_max.checked_start_of_next_day(gmt), None);
+    assert_eq!(
+        Time::MAX.checked_truncate(CalendarUnit::Hour, Zone::fixed(3600).unwrap()),
+        Some(t("2262-04-11 23:00:00Z"))
+    );
+    assert_eq!(Time::MAX.checked_truncate(CalendarUnit::Day, Tz::Asia__Hong_Kong), None);
+
+    let ny = Tz::America__New_York;
+    assert_eq!(Time::MIN.checked_truncate(CalendarUnit::Minute, gmt), None);
+    let up = Time::MIN.checked_round_to(CalendarUnit::Minute, RoundingMode::Up, gmt).unwrap();
+    let ofday = OfDay::from_str("00:13:00").unwrap();
+    assert_eq!(up.to_date_ofday_gmt(), (Date::from_str("1677-09-21").unwrap(), ofday));
+    assert_eq!(Time::MIN.checked_truncate(CalendarUnit::Year, gmt), None);
+    assert_eq!(Time::MIN.checked_truncate(CalendarUnit::Day, ny), None);
+    assert_eq!(Time::MIN.checked_start_of_next_day(ny), None);
+    let near_min = Time::MIN + Span::DAY * 3;
+    assert_eq!(near_min.checked_start_of_next_day(gmt), Some(t("1677-09-25 00:00:00Z")));
+}
+
+#[test]
+#[should_panic(expected = "time out of range")]
+fn start_of_next_day_panics_near_max() {
+    Time::MAX.start_of_next_day(Tz::GMT);
+}
@@ -0,0 +1,80 @@
+use std::str::FromStr;
+use timens::{Date, OfDay, Span, Time, Tz, TzError, ZonedOfDay};
+
+#[test]
+fn parse_and_display() {
+    let open = ZonedOfDay::from_str("09:30 America/New_York").unwrap();
+    assert_eq!(open.ofday(), OfDay::from_str("09:30").unwrap());
+    assert_eq!(open.tz(), Tz::America__New_York);
+    assert_eq!(open.to_string(), "09:30:00 America/New_York");
+    assert_eq!(ZonedOfDay::from_str(&open.to_string()), Ok(open));
+    assert!(ZonedOfDay::from_str("09:30").is_err());
+    assert!(ZonedOfDay::from_str("09:30 Mars/Olympus").is_err());
+}
+
+#[test]
+fn to_time() {
+    let open = ZonedOfDay::from_str("09:30 America/New_York").unwrap();
+    let date = Date::from_str("2021-08-27").unwrap();
+    assert_eq!(open.to_time(date).unwrap().to_string(), "2021-08-27 13:30:00Z");
+    let skipped = ZonedOfDay::from_str("01:30 Europe/London").unwrap();
+    let date = Date::from_str("2021-03-28").unwrap();
+    assert_eq!(skipped.to_time(date), Err(TzError::NoTimeInThisTz));
+    assert_eq!(skipped.to_time_lenient(date).to_string(), "2021-03-28 01:30:00Z");
+}
+
+#[test]
+fn next_occurrence() {
+    let open = ZonedOfDay::from_str("09:30 America/New_York").unwrap();
+    let next =
+        |after: &str| open.next_occurrence(Time::from_str(after).unwrap()).unwrap().to_string();
+    assert_eq!(next("2021-08-27 13:00:00Z"), "2021-08-27 13:30:00Z");
+    assert_eq!(next("2021-08-27 13:30:00Z"), "2021-08-28 13:30:00Z");
+    assert_eq!(next("2021-08-28 03:00:00Z"), "2021-08-28 13:30:00Z");
+    // Crossing the switch to daylight saving time.
+    assert_eq!(next("2021-03-13 15:00:00Z"), "2021-03-14 13:30:00Z");
+    // The local time is skipped on 2021-03-28 in London.
+    let skipped = ZonedOfDay::from_str("01:30 Europe/London").unwrap();
+    let next = skipped.next_occurrence(Time::from_str("2021-03-27 02:00:00Z").unwrap()).unwrap();
+    assert_eq!(next.to_string(), "2021-03-29 00:30:00Z");
+    // And repeated on 2021-10-31.
+    let repeated = ZonedOfDay::from_str("01:30 Europe/London").unwrap();
+    let first = repeated.next_occurrence(Time::from_str("2021-10-30 12:00:00Z").unwrap()).unwrap();
+    assert_eq!(first.to_string(), "2021-10-31 00:30:00Z");
+    assert_eq!(repeated.next_occurrence(first).unwrap().to_string(), "2021-10-31 01:30:00Z");
+    // No panic at the ends of the time range.
+    assert_eq!(open.next_occurrence(Time::MAX), None);
+    assert_eq!(open.next_occurrence(Time::MAX - Span::DAY * 2), None);
+    let next = open.next_occurrence(Time::MAX - Span::DAY * 5).unwrap();
+    assert!(next > Time::MAX - Span::DAY * 5);
+    let next = open.next_occurrence(Time::MIN).unwrap();
+    assert_eq!(next.to_ofday(Tz::America__New_York).to_string(), "09:30:00");
+}
+
+#[cfg(feature = "binio")]
+#[test]
+fn binio_roundtrip() {
+    let open = ZonedOfDay::from_str("09:30 America/New_York").unwrap();
+    let mut bytes: Vec<u8> = vec![];
+    binprot::BinProtWrite::binprot_write(&open, &mut bytes).unwrap();
+    let mut expected: Vec<u8> = vec![];
+    binprot::BinProtWrite::binprot_write(&open.ofday(), &mut expected).unwrap();
+    binprot::BinProtWrite::binprot_write(&"America/New_York".to_string(), &mut expected).unwrap();
+    assert_eq!(bytes, expected);
+    let rt: ZonedOfDay = binprot::BinProtRead::binprot_read(&mut bytes.as_slice()).unwrap();
+    assert_eq!(open, rt);
+}
+
+#[cfg(feature = "sexp")]
+#[test]
+fn sexp_roundtrip() {
+    let open = ZonedOfDay::from_str("09:30 America/New_York").unwrap();
+    let sexp = rsexp::SexpOf::sexp_of(&open);
+    assert_eq!(sexp.to_string(), "(09:30:00 America/New_York)");
+    let rt: ZonedOfDay = rsexp::OfSexp::of_sexp(&sexp).unwrap();
+    assert_eq!(open, rt);
+    // OCaml prints the time of day with a nanosecond precision.
+    let sexp = rsexp::from_slice(b"(09:30:00.000000000 America/New_York)").unwrap();
+    let rt: ZonedOfDay = rsexp::OfSexp::of_sexp(&sexp).unwrap();
+    assert_eq!(open, rt);
+}
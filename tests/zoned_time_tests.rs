@@ -0,0 +1,117 @@
+use std::str::FromStr;
+use timens::{Date, FixedOffset, OfDay, Span, Time, TimeParseError, Tz, Zone, ZonedTime};
+
+#[test]
+fn zone() {
+    assert_eq!(Zone::from_str("Europe/London"), Ok(Zone::Tz(Tz::Europe__London)));
+    assert_eq!(Zone::from_str("+05:30"), Ok(Zone::fixed(19800).unwrap()));
+    assert_eq!(Zone::from_str("-04:00"), Ok(Zone::fixed(-14400).unwrap()));
+    assert_eq!(Zone::from_str("Z"), Ok(Zone::UTC));
+    assert!(Zone::from_str("Mars/Olympus").is_err());
+    assert_eq!(Zone::fixed(19800).unwrap().to_string(), "+05:30");
+    assert_eq!(Zone::Tz(Tz::Asia__Hong_Kong).to_string(), "Asia/Hong_Kong");
+    let time = Time::from_str("2021-07-01 12:00:00Z").unwrap();
+    assert_eq!(Zone::Tz(Tz::America__New_York).offset(time), -Span::HR * 4);
+}
+
+#[test]
+fn fixed_offset_bounds() {
+    assert_eq!(Zone::fixed(i32::MAX), None);
+    assert_eq!(Zone::fixed(i32::MIN), None);
+    assert_eq!(Zone::fixed(18 * 3600 + 1), None);
+    assert_eq!(Zone::fixed(-18 * 3600).unwrap().to_string(), "-18:00");
+    assert_eq!(FixedOffset::of_sec(19800).map(FixedOffset::to_sec), Some(19800));
+    assert_eq!(Zone::from_str("+18:00"), Ok(Zone::fixed(18 * 3600).unwrap()));
+    assert_eq!(
+        Zone::from_str("+18:00:01"),
+        Err(TimeParseError::InvalidZoneOffset(Span::of_int_sec(64801)))
+    );
+    assert_eq!(Zone::from_str("-99:00"), Err(TimeParseError::InvalidZoneOffset(-Span::HR * 99)));
+    assert!(ZonedTime::from_str("2021-08-27 10:00:00+25:00").is_err());
+}
+
+#[test]
+fn roundtrip_string() {
+    let check = |s: &str| {
+        let zoned = ZonedTime::from_str(s).unwrap();
+        assert_eq!(zoned.to_string(), s);
+        assert_eq!(ZonedTime::from_str(&zoned.to_string()), Ok(zoned));
+    };
+    check("2021-01-01 10:00:00 Europe/London");
+    check("2021-07-01 10:00:00 Europe/London");
+    check("2021-01-01 15:30:00+05:30");
+    check("2021-01-01 15:30:00.123-04:00");
+    // The offset disambiguates local times repeated when leaving daylight saving time.
+    check("2021-10-31 01:30:00+01:00 Europe/London");
+    check("2021-10-31 01:30:00+00:00 Europe/London");
+    let zoned = ZonedTime::from_str("2021-01-01T10:00:00 America/New_York").unwrap();
+    assert_eq!(zoned.time(), Time::from_str("2021-01-01 15:00:00Z").unwrap());
+    assert_eq!(zoned.zone(), Zone::Tz(Tz::America__New_York));
+    assert_eq!(
+        ZonedTime::from_str("2021-01-01 10:00:00+01:00 Europe/London"),
+        Err(TimeParseError::ZoneOffsetMismatch(Span::ZERO))
+    );
+    assert_eq!(ZonedTime::from_str("2021-01-01 10:00:00"), Err(TimeParseError::NoZone));
+}
+
+#[test]
+fn local_accessors() {
+    let time = Time::from_str("2021-08-27 23:30:00Z").unwrap();
+    let zoned = time.to_zoned(Zone::Tz(Tz::Asia__Hong_Kong));
+    assert_eq!(zoned.date(), Date::from_str("2021-08-28").unwrap());
+    assert_eq!(zoned.ofday(), OfDay::from_str("07:30").unwrap());
+    assert_eq!(zoned.date_time().to_string(), "2021-08-28 07:30:00");
+    assert_eq!(zoned.offset(), Span::HR * 8);
+    assert_eq!(
+        zoned.with_zone(Zone::fixed(-3600).unwrap()).to_string(),
+        "2021-08-27 22:30:00-01:00"
+    );
+    assert_eq!(Time::from(zoned), time);
+}
+
+#[test]
+fn calendar_arithmetic() {
+    let ny = Zone::Tz(Tz::America__New_York);
+    let zoned = ZonedTime::of_date_ofday(
+        Date::from_str("2021-03-13").unwrap(),
+        OfDay::from_str("09:30").unwrap(),
+        ny,
+    )
+    .unwrap();
+    // Crossing the switch to daylight saving time, a calendar day is only 23 hours.
+    assert_eq!(zoned.add_days(1).to_string(), "2021-03-14 09:30:00 America/New_York");
+    assert_eq!(zoned.add_days(1) - zoned, Span::HR * 23);
+    assert_eq!((zoned + Span::DAY).to_string(), "2021-03-14 10:30:00 America/New_York");
+    assert_eq!(zoned.add_months(1).to_string(), "2021-04-13 09:30:00 America/New_York");
+    assert_eq!(zoned.add_years(-1).to_string(), "2020-03-13 09:30:00 America/New_York");
+    let jan31 = ZonedTime::from_str("2021-01-31 12:00:00 Europe/London").unwrap();
+    assert_eq!(jan31.add_months(1).to_string(), "2021-02-28 12:00:00 Europe/London");
+    // Skipped local times are shifted forward.
+    let zoned = ZonedTime::from_str("2021-03-27 02:30:00 America/New_York").unwrap();
+    assert_eq!(
+        zoned.add_months(-1).add_days(-13).to_string(),
+        "2021-02-14 02:30:00 America/New_York"
+    );
+    let zoned = ZonedTime::from_str("2021-03-28 01:30:00 Europe/London");
+    assert!(zoned.is_err());
+    let zoned = ZonedTime::from_str("2021-03-27 01:30:00 Europe/London").unwrap();
+    assert_eq!(zoned.add_days(1).to_string(), "2021-03-28 02:30:00 Europe/London");
+}
+
+#[cfg(feature = "sexp")]
+#[test]
+fn sexp_roundtrip() {
+    let check = |s: &str, sexp_str: &str| {
+        let zoned = ZonedTime::from_str(s).unwrap();
+        let sexp = rsexp::SexpOf::sexp_of(&zoned);
+        assert_eq!(sexp.to_string(), sexp_str);
+        let rt: ZonedTime = rsexp::OfSexp::of_sexp(&sexp).unwrap();
+        assert_eq!(zoned, rt)
+    };
+    check("2021-01-01 10:00:00 Europe/London", "(2021-01-01 10:00:00 Europe/London)");
+    check("2021-01-01 15:30:00+05:30", "(2021-01-01 15:30:00+05:30)");
+    // The sexp of a time can be read as a zoned time with a zero offset.
+    let time = Time::from_str("2021-01-01 10:00:00Z").unwrap();
+    let rt: ZonedTime = rsexp::OfSexp::of_sexp(&rsexp::SexpOf::sexp_of(&time)).unwrap();
+    assert_eq!(rt, time.to_zoned(Zone::UTC));
+}